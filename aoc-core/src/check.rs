@@ -0,0 +1,52 @@
+use std::{
+    any::type_name,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+/// Compares a solver's answer with the expected answer written in an `#[aoc]` attribute.
+///
+/// Answers whose type implements `FromStr` are compared as values, so `"0042"` matches `42`.
+/// Any other answer falls back to comparing its `Display` output. The right comparison is picked
+/// by calling `(&Answer(&value)).check(...)` with both traits in scope.
+pub struct Answer<'a, T>(pub &'a T);
+
+pub trait ParsedAnswer {
+    fn check(&self, expected: &str, solver: &str, file: &str);
+}
+
+impl<T: FromStr + PartialEq + Debug> ParsedAnswer for Answer<'_, T> {
+    fn check(&self, expected: &str, solver: &str, file: &str) {
+        let Ok(parsed) = expected.parse::<T>() else {
+            panic!(
+                "expected answer {:?} for {} is not a valid {}",
+                expected,
+                solver,
+                type_name::<T>()
+            );
+        };
+        if *self.0 != parsed {
+            mismatch(solver, file, &format!("{:?}", parsed), &format!("{:?}", self.0));
+        }
+    }
+}
+
+pub trait DisplayedAnswer {
+    fn check(&self, expected: &str, solver: &str, file: &str);
+}
+
+impl<T: Display> DisplayedAnswer for &Answer<'_, T> {
+    fn check(&self, expected: &str, solver: &str, file: &str) {
+        let answer = self.0.to_string();
+        if answer != expected {
+            mismatch(solver, file, expected, &answer);
+        }
+    }
+}
+
+fn mismatch(solver: &str, file: &str, expected: &str, answer: &str) -> ! {
+    panic!(
+        "{} gave the wrong answer on {}\n expected: {}\n      got: {}",
+        solver, file, expected, answer
+    );
+}
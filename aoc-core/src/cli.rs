@@ -0,0 +1,71 @@
+use std::process::ExitCode;
+
+use crate::{
+    input::resolve,
+    parse::report,
+    registry::{solvers, ParsedInputs, Solver},
+};
+
+const USAGE: &str = "usage: [--part <1|2>] [--input <path>|-] [--time]";
+
+struct Args {
+    part: Option<u8>,
+    input: Option<String>,
+    time: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args { part: None, input: None, time: false };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part needs a value")?;
+                parsed.part = Some(part.parse().map_err(|_| format!("invalid part {:?}", part))?);
+            }
+            "--input" | "-i" => parsed.input = Some(args.next().ok_or("--input needs a path")?),
+            "--time" | "-t" => parsed.time = true,
+            _ => return Err(format!("unexpected argument {:?}", arg)),
+        }
+    }
+    Ok(parsed)
+}
+
+/// Entry point generated by `aoc_macros::main!` for the solvers of `krate`.
+pub fn main(krate: &str) -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let parts: Vec<&Solver> = solvers()
+        .into_iter()
+        .filter(|s| s.krate == krate && args.part.is_none_or(|p| s.part == p))
+        .collect();
+    let Some(first) = parts.first() else {
+        eprintln!("no solvers found for {}", args.part.map_or("any part".to_string(), |p| format!("part {}", p)));
+        return ExitCode::FAILURE;
+    };
+    let input = match resolve(args.input.as_deref(), first.dir) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut code = ExitCode::SUCCESS;
+    let parsed = ParsedInputs::default();
+    for solver in parts {
+        let (result, times) = solver.run_on(&input, &parsed);
+        match result {
+            Ok(answer) if args.time => println!("Part {}: {} ({})", solver.part, answer, times),
+            Ok(answer) => println!("Part {}: {}", solver.part, answer),
+            Err(e) => {
+                eprintln!("Part {} failed: {}", solver.part, report(&*e, &input));
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    code
+}
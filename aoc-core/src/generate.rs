@@ -0,0 +1,31 @@
+use linkme::distributed_slice;
+
+pub use fastrand::Rng;
+
+/// A generator of random inputs for a day, registered by `#[generator]`.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// Name of the crate the generator lives in, e.g. `day-05`.
+    pub krate: &'static str,
+    /// Size of a generated input when none is asked for, about that of a real input.
+    pub size: usize,
+    /// Size of the small inputs solvers are tested on, and checked against their references with.
+    pub test_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// The input generated with `seed`, `size` defaulting to that of a real input. The same seed
+    /// and size always give the same input.
+    pub fn input(&self, size: Option<usize>, seed: u64) -> String {
+        (self.generate)(&mut Rng::with_seed(seed), size.unwrap_or(self.size))
+    }
+}
+
+#[distributed_slice]
+pub static GENERATORS: [Generator];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.year == year && g.day == day)
+}
@@ -0,0 +1,53 @@
+use std::{
+    env, fs,
+    io::{self, IsTerminal, Read},
+    path::Path,
+};
+
+/// Environment variable with the path of the input, used when none is given on the command line
+/// and nothing is piped in.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Turns CRLF line endings into LF and drops trailing newlines, so solvers splitting on `"\n\n"`
+/// or `','` see the same input however the file was saved.
+pub fn normalize(input: &str) -> String {
+    input.replace("\r\n", "\n").trim_end_matches('\n').to_string()
+}
+
+pub fn read_file(path: impl AsRef<Path>) -> Result<String, String> {
+    let path = path.as_ref();
+    fs::read_to_string(path)
+        .map(|input| normalize(&input))
+        .map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+pub fn read_stdin() -> Result<String, String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).map_err(|e| format!("could not read stdin: {}", e))?;
+    Ok(normalize(&input))
+}
+
+/// Reads `input.txt` in `dir`, the crate directory of a solver.
+pub fn read_default(dir: &str) -> Result<String, String> {
+    read_file(Path::new(dir).join("input.txt"))
+}
+
+/// Finds the input of a run, trying in order: `path` (`-` being stdin), stdin when something is
+/// piped in, the file in [`INPUT_VAR`], and `input.txt` in `dir`.
+pub fn resolve(path: Option<&str>, dir: &str) -> Result<String, String> {
+    match path {
+        Some("-") => return read_stdin(),
+        Some(path) => return read_file(path),
+        None => {}
+    }
+    if !io::stdin().is_terminal() {
+        let input = read_stdin()?;
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+    match env::var(INPUT_VAR) {
+        Ok(path) => read_file(path),
+        Err(_) => read_default(dir),
+    }
+}
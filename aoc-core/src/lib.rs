@@ -0,0 +1,12 @@
+pub mod check;
+pub mod cli;
+pub mod generate;
+pub mod input;
+pub mod parse;
+pub mod registry;
+pub mod trace;
+
+pub use generate::{Generator, GENERATORS};
+pub use linkme;
+pub use parse::ParseError;
+pub use registry::{Puzzle, Reference, Solver, SOLVERS, YEAR};
@@ -0,0 +1,139 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Input that doesn't look like a solver expects, with where that went wrong.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    /// Line of the input, counting from 1.
+    pub line: usize,
+    /// Character on that line, counting from 1.
+    pub column: usize,
+    /// What should have been there, e.g. `a cube count`.
+    pub expected: String,
+    /// What was there instead; empty when the line ended too soon.
+    pub found: String,
+}
+
+/// Lines and characters in `text` before `part`, which should be a slice of `text`.
+fn offset(text: &str, part: &str) -> (usize, usize) {
+    let start = (part.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    let start = match start <= text.len() && text.is_char_boundary(start) {
+        true => start,
+        false => text.find(part).unwrap_or(0),
+    };
+    let before = &text[..start];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (before.matches('\n').count(), before[line_start..].chars().count())
+}
+
+impl ParseError {
+    /// An error about `found`, a part of the `text` being parsed.
+    pub fn at(text: &str, found: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = offset(text, found);
+        Self { line: line + 1, column: column + 1, expected: expected.into(), found: found.to_string() }
+    }
+
+    /// An error about `text` ending before `expected`.
+    pub fn end(text: &str, expected: impl Into<String>) -> Self {
+        Self::at(text, &text[text.len()..], expected)
+    }
+
+    /// An error about line `index` of `text`, counting from 0, not having the `width` characters
+    /// of the rows of a grid before it.
+    pub fn ragged(text: &str, index: usize, width: usize) -> Self {
+        let line = text.lines().nth(index).unwrap_or_default();
+        match line.char_indices().nth(width) {
+            Some((end, _)) => Self::at(text, &line[end..], format!("the end of a row of {} characters", width)),
+            None => Self::at(text, &line[line.len()..], format!("a row of {} characters", width)),
+        }
+    }
+
+    /// An error about the character in row `row` and column `col` of `text`, counting from 0, as
+    /// for a cell of a grid.
+    pub fn cell(text: &str, row: usize, col: usize, expected: impl Into<String>) -> Self {
+        let line = text.lines().nth(row).unwrap_or_default();
+        let found = match line.char_indices().nth(col) {
+            Some((i, c)) => &line[i..i + c.len_utf8()],
+            None => &line[line.len()..],
+        };
+        Self::at(text, found, expected)
+    }
+
+    /// Moves an error found while parsing `part` on its own to where `part` is in `text`.
+    pub fn inside(mut self, text: &str, part: &str) -> Self {
+        let (line, column) = offset(text, part);
+        if self.line == 1 {
+            self.column += column;
+        }
+        self.line += line;
+        self
+    }
+
+    /// The error followed by its line of `input`, with carets under what was found.
+    pub fn snippet(&self, input: &str) -> String {
+        let Some(text) = input.lines().nth(self.line - 1) else {
+            return self.to_string();
+        };
+        let number = self.line.to_string();
+        let pad = " ".repeat(number.len());
+        let carets = "^".repeat(self.found.chars().count().max(1));
+        format!(
+            "{}\n{} |\n{} | {}\n{} | {}{}",
+            self,
+            pad,
+            number,
+            text,
+            pad,
+            " ".repeat(self.column - 1),
+            carets
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+        match self.found.is_empty() {
+            true => write!(f, "found the end of the line"),
+            false => write!(f, "found {:?}", self.found),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `found`, a part of `text`, or reports that it isn't `expected`.
+pub fn field<T: FromStr>(text: &str, found: &str, expected: &str) -> Result<T, ParseError> {
+    found.parse().map_err(|_| ParseError::at(text, found, expected))
+}
+
+/// Parses every line of `text` on its own.
+pub fn lines<T: FromStr<Err = ParseError>>(text: &str) -> Result<Vec<T>, ParseError> {
+    text.lines()
+        .map(|line| line.parse().map_err(|e: ParseError| e.inside(text, line)))
+        .collect()
+}
+
+/// How to show a solver's error for `input`: with a snippet of the input for a [`ParseError`].
+pub fn report(error: &(dyn Error + Send + Sync + 'static), input: &str) -> String {
+    match error.downcast_ref::<ParseError>() {
+        Some(e) => e.snippet(input),
+        None => error.to_string(),
+    }
+}
+
+/// What a day's `parse` function may return: the parsed input itself, or a `Result` of it.
+pub trait IntoParsed<T> {
+    fn into_parsed(self) -> Result<T, Box<dyn Error + Send + Sync>>;
+}
+
+impl<T> IntoParsed<T> for T {
+    fn into_parsed(self) -> Result<T, Box<dyn Error + Send + Sync>> {
+        Ok(self)
+    }
+}
+
+impl<T, E: Into<Box<dyn Error + Send + Sync>>> IntoParsed<T> for Result<T, E> {
+    fn into_parsed(self) -> Result<T, Box<dyn Error + Send + Sync>> {
+        self.map_err(Into::into)
+    }
+}
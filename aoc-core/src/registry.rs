@@ -0,0 +1,149 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    error::Error,
+    fmt,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use linkme::distributed_slice;
+
+use crate::parse::ParseError;
+
+pub type SolverResult = Result<String, Box<dyn Error + Send + Sync>>;
+
+/// Parses an input and solves a part on it.
+pub type Run = fn(&str) -> (SolverResult, Times);
+
+/// An input as a day's parser returned it.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// How long a solver spent parsing its input and solving the puzzle. Solvers taking the raw input
+/// spend no time parsing.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Times {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Times {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+impl fmt::Display for Times {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parse {:.2?}, solve {:.2?}", self.parse, self.solve)
+    }
+}
+
+/// The year of the days at the root of the repository. Days of other years live in a directory
+/// named after their year, in crates named like `aoc2022-day-05`.
+pub const YEAR: u16 = 2023;
+
+/// A day of some year, shown as `Day 5` for a day of [`YEAR`] and as `2022 day 5` otherwise.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.year {
+            YEAR => write!(f, "Day {}", self.day),
+            year => write!(f, "{} day {}", year, self.day),
+        }
+    }
+}
+
+/// A solver registered by `#[aoc]`.
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Name of the solver function, e.g. `part_1`.
+    pub name: &'static str,
+    /// Name of the crate the solver lives in, e.g. `day-05`.
+    pub krate: &'static str,
+    /// Directory of that crate.
+    pub dir: &'static str,
+    /// Example file the solver is tested on, relative to `dir`.
+    pub example: Option<&'static str>,
+    /// Accepted answer for the real input.
+    pub answer: Option<&'static str>,
+    pub run: Run,
+    /// The parsing and solving `run` does, apart, for a solver taking parsed input.
+    pub parsed: Option<Parse>,
+    /// Naive solver this one is checked against, set with `#[aoc(reference = ...)]`.
+    pub reference: Option<Reference>,
+}
+
+/// How a solver gets its parsed input, so that solvers of a day using the same parser can share it.
+pub struct Parse {
+    /// Path of the parser, e.g. `parse`.
+    pub parser: &'static str,
+    pub parse: fn(&str) -> Result<Parsed, Box<dyn Error + Send + Sync>>,
+    /// Runs the solver on what `parse` returned.
+    pub solve: fn(&Parsed) -> SolverResult,
+}
+
+/// A naive solver for the same part, slow but free of the shortcuts the solver takes.
+pub struct Reference {
+    /// Runs the naive solver.
+    pub run: Run,
+    /// Runs the solver itself, with the extra argument the naive solver gets.
+    pub solver: Run,
+}
+
+impl Solver {
+    pub fn puzzle(&self) -> Puzzle {
+        Puzzle { year: self.year, day: self.day }
+    }
+
+    /// Runs the solver like `run`, but parses `inp` only once for all solvers of the day that run on
+    /// it with the same `parsed`. The parse time is reported to each of them.
+    pub fn run_on(&self, inp: &str, parsed: &ParsedInputs) -> (SolverResult, Times) {
+        let Some(parse) = &self.parsed else {
+            return (self.run)(inp);
+        };
+        let cell = parsed.0.lock().unwrap().entry((self.puzzle(), parse.parser)).or_default().clone();
+        let (input, time) = cell.get_or_init(|| {
+            let start = Instant::now();
+            ((parse.parse)(inp), start.elapsed())
+        });
+        let start = Instant::now();
+        let answer = match input {
+            Ok(input) => (parse.solve)(input),
+            // Every solver gets the error, as a copy that still shows where the input went wrong.
+            Err(e) => Err(match e.downcast_ref::<ParseError>() {
+                Some(e) => Box::new(e.clone()) as Box<dyn Error + Send + Sync>,
+                None => e.to_string().into(),
+            }),
+        };
+        (answer, Times { parse: *time, solve: start.elapsed() })
+    }
+}
+
+/// What a parser made of an input, and how long it took, once it has run.
+type ParsedInput = Arc<OnceLock<(Result<Parsed, Box<dyn Error + Send + Sync>>, Duration)>>;
+
+/// What the parsers of the days have made of one input so far.
+#[derive(Default)]
+pub struct ParsedInputs(Mutex<HashMap<(Puzzle, &'static str), ParsedInput>>);
+
+#[distributed_slice]
+pub static SOLVERS: [Solver];
+
+/// All registered solvers, ordered by year, day and part.
+pub fn solvers() -> Vec<&'static Solver> {
+    let mut solvers: Vec<_> = SOLVERS.iter().collect();
+    solvers.sort_by_key(|s| (s.year, s.day, s.part));
+    solvers
+}
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.year == year && s.day == day && s.part == part)
+}
@@ -0,0 +1,166 @@
+//! Structured events from inside the solvers, for finding out why an input gives a wrong answer.
+//!
+//! Solvers call [`trace!`](crate::trace!) in their key loops. Without the `trace` feature the
+//! macro expands to nothing that runs and its arguments are never evaluated; with it, every event
+//! goes to the sink [`install`] set up as a line of JSON, tagged with the year, day and part that
+//! [`scope`] runs.
+
+use std::{
+    cell::Cell,
+    fmt::Write as _,
+    io::Write,
+    sync::Mutex,
+};
+
+/// Whether the crate was built with the `trace` feature, so events are written at all.
+pub const ENABLED: bool = cfg!(feature = "trace");
+
+/// A field of an event.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Uint(u64),
+    Bool(bool),
+    Str(String),
+}
+
+macro_rules! from_int {
+    ($variant:ident: $($ty:ty),*) => {
+        $(impl From<$ty> for Value {
+            fn from(value: $ty) -> Self {
+                Value::$variant(value as _)
+            }
+        })*
+    };
+}
+
+from_int!(Int: i8, i16, i32, i64, isize);
+from_int!(Uint: u8, u16, u32, u64, usize);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        Value::Str(value.to_string())
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Str(value.to_string())
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Self {
+        Value::Str(value.clone())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Str(value)
+    }
+}
+
+fn quote(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+impl Value {
+    fn write_json(&self, json: &mut String) {
+        match self {
+            Value::Int(n) => write!(json, "{}", n).unwrap(),
+            Value::Uint(n) => write!(json, "{}", n).unwrap(),
+            Value::Bool(b) => write!(json, "{}", b).unwrap(),
+            Value::Str(s) => quote(json, s),
+        }
+    }
+}
+
+static SINK: Mutex<Option<Box<dyn Write + Send>>> = Mutex::new(None);
+
+thread_local! {
+    static SCOPE: Cell<Option<(u16, u8, u8)>> = const { Cell::new(None) };
+}
+
+/// Sends the events of every thread to `sink` from now on.
+pub fn install(sink: Box<dyn Write + Send>) {
+    *SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(sink);
+}
+
+/// Flushes the sink, and stops writing to it.
+pub fn finish() -> std::io::Result<()> {
+    match SINK.lock().unwrap_or_else(|e| e.into_inner()).take() {
+        Some(mut sink) => sink.flush(),
+        None => Ok(()),
+    }
+}
+
+/// Runs `f`, tagging the events it emits on this thread with `year`, `day` and `part`.
+pub fn scope<T>(year: u16, day: u8, part: u8, f: impl FnOnce() -> T) -> T {
+    if !ENABLED {
+        return f();
+    }
+    let outer = SCOPE.replace(Some((year, day, part)));
+    let result = f();
+    SCOPE.set(outer);
+    result
+}
+
+/// Writes an event to the sink, if one is installed. Called by [`trace!`](crate::trace!).
+pub fn emit(event: &str, fields: &[(&str, Value)]) {
+    let mut sink = SINK.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(sink) = sink.as_mut() else {
+        return;
+    };
+    let mut json = String::from("{");
+    if let Some((year, day, part)) = SCOPE.get() {
+        write!(json, "\"year\":{},\"day\":{},\"part\":{},", year, day, part).unwrap();
+    }
+    json.push_str("\"event\":");
+    quote(&mut json, event);
+    for (key, value) in fields {
+        json.push(',');
+        quote(&mut json, key);
+        json.push(':');
+        value.write_json(&mut json);
+    }
+    json.push('}');
+    // A full disk shouldn't take the solver down with it.
+    let _ = writeln!(sink, "{}", json);
+}
+
+/// Emits a structured event named `$event` with `key = value` fields, whose values are anything
+/// that converts into a [`Value`](crate::trace::Value):
+///
+/// ```
+/// # let (press, high) = (1, false);
+/// aoc_core::trace!("pulse", press = press, from = "broadcaster", high = high);
+/// ```
+///
+/// Without the `trace` feature of `aoc-core` nothing is evaluated.
+#[macro_export]
+macro_rules! trace {
+    ($event:literal $(, $key:ident = $value:expr)* $(,)?) => {
+        // A constant, so this is gone without the feature, but the fields are still type checked
+        // and don't go stale while nobody traces.
+        if $crate::trace::ENABLED {
+            $crate::trace::emit($event, &[$((stringify!($key), $crate::trace::Value::from($value))),*])
+        }
+    };
+}
@@ -0,0 +1,119 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::WeightedGraph;
+
+/// The lightest set of edges whose removal splits the graph in two, as its total weight and the
+/// nodes on one side of it. The graph has to be undirected, listing every edge both ways, and
+/// connected; `nodes` has to contain at least two nodes of it.
+///
+/// This is the Stoer-Wagner algorithm, so unlike random contraction it gives the same cut every
+/// time.
+pub fn min_cut<G: WeightedGraph>(
+    graph: &G,
+    nodes: impl IntoIterator<Item = G::Node>,
+) -> (G::Weight, HashSet<G::Node>) {
+    let nodes: Vec<G::Node> = nodes.into_iter().collect();
+    assert!(nodes.len() >= 2, "a cut needs at least two nodes");
+    let index: HashMap<&G::Node, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut adjacent: Vec<HashMap<usize, G::Weight>> = nodes
+        .iter()
+        .map(|node| {
+            graph
+                .edges(node)
+                .into_iter()
+                .map(|(n, weight)| (index[&n], weight))
+                .collect()
+        })
+        .collect();
+    // The original nodes that have been merged into each node.
+    let mut merged: Vec<Vec<usize>> = (0..nodes.len()).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..nodes.len()).collect();
+    let mut best: Option<(G::Weight, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        // Add the node most tightly connected to the ones added so far, until all are added.
+        let mut added = vec![false; nodes.len()];
+        let mut connection = vec![G::Weight::default(); nodes.len()];
+        let mut queue = BinaryHeap::from([(G::Weight::default(), active[0])]);
+        let (mut prev, mut last) = (active[0], active[0]);
+        let mut count = 0;
+        while count < active.len() {
+            let (weight, v) = queue.pop().expect("graph is not connected");
+            if added[v] || weight != connection[v] {
+                continue;
+            }
+            added[v] = true;
+            count += 1;
+            (prev, last) = (last, v);
+            for (&n, &w) in &adjacent[v] {
+                if !added[n] {
+                    connection[n] = connection[n] + w;
+                    queue.push((connection[n], n));
+                }
+            }
+        }
+
+        // The last node on its own is the lightest cut between it and the one before.
+        if best.as_ref().is_none_or(|(weight, _)| connection[last] < *weight) {
+            best = Some((connection[last], merged[last].clone()));
+        }
+
+        // Merge the last node into the one before it.
+        for (n, w) in std::mem::take(&mut adjacent[last]) {
+            adjacent[n].remove(&last);
+            if n == prev {
+                continue;
+            }
+            let e = adjacent[prev].entry(n).or_default();
+            *e = *e + w;
+            let e = adjacent[n].entry(prev).or_default();
+            *e = *e + w;
+        }
+        let moved = std::mem::take(&mut merged[last]);
+        merged[prev].extend(moved);
+        active.retain(|&v| v != last);
+    }
+
+    let (weight, side) = best.unwrap();
+    (weight, side.into_iter().map(|i| nodes[i].clone()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn undirected(edges: &[(char, char, u32)]) -> HashMap<char, Vec<(char, u32)>> {
+        let mut graph: HashMap<char, Vec<(char, u32)>> = HashMap::new();
+        for &(a, b, weight) in edges {
+            graph.entry(a).or_default().push((b, weight));
+            graph.entry(b).or_default().push((a, weight));
+        }
+        graph
+    }
+
+    #[test]
+    fn two_triangles() {
+        // Two triangles joined by the single edge c-d.
+        let graph = undirected(&[
+            ('a', 'b', 1),
+            ('b', 'c', 1),
+            ('c', 'a', 1),
+            ('c', 'd', 1),
+            ('d', 'e', 1),
+            ('e', 'f', 1),
+            ('f', 'd', 1),
+        ]);
+        let (weight, side) = min_cut(&graph, "abcdef".chars());
+        assert_eq!(weight, 1);
+        let abc = HashSet::from(['a', 'b', 'c']);
+        let def = HashSet::from(['d', 'e', 'f']);
+        assert!(side == abc || side == def, "{:?}", side);
+    }
+
+    #[test]
+    fn weights() {
+        // Cutting off a costs 2 + 2, cutting off d costs 1 + 1, and every other cut crosses b-c.
+        let graph = undirected(&[('a', 'b', 2), ('a', 'c', 2), ('b', 'c', 5), ('b', 'd', 1), ('c', 'd', 1)]);
+        assert_eq!(min_cut(&graph, "abcd".chars()), (2, HashSet::from(['d'])));
+    }
+}
@@ -0,0 +1,53 @@
+/// The number of steps along `next` from `start` until a node matching `is_goal`, not counting
+/// `start` itself, or `None` when that takes more than `limit` steps. A walk through `n` different
+/// nodes that hasn't found the goal after `n` steps is going round in circles.
+pub fn steps_until<N>(
+    start: N,
+    mut next: impl FnMut(&N) -> N,
+    mut is_goal: impl FnMut(&N) -> bool,
+    limit: u64,
+) -> Option<u64> {
+    let mut node = start;
+    for steps in 1..=limit {
+        node = next(&node);
+        if is_goal(&node) {
+            return Some(steps);
+        }
+    }
+    None
+}
+
+/// The least common multiple of `values`: after how many steps cycles of these lengths line up.
+pub fn lcm(values: impl IntoIterator<Item = u64>) -> u64 {
+    fn gcd(a: u64, b: u64) -> u64 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+    values.into_iter().fold(1, |acc, v| acc / gcd(acc, v) * v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps() {
+        let next = |&n: &u32| (n + 1) % 5;
+        assert_eq!(steps_until(0, next, |&n| n == 3, 10), Some(3));
+        // The start itself doesn't count.
+        assert_eq!(steps_until(0, next, |&n| n == 0, 10), Some(5));
+        assert_eq!(steps_until(0, next, |&n| n == 3, 2), None);
+        assert_eq!(steps_until(0, next, |&n| n == 7, 1000), None);
+    }
+
+    #[test]
+    fn least_common_multiple() {
+        assert_eq!(lcm([2, 3, 4]), 12);
+        assert_eq!(lcm([4, 6]), 12);
+        assert_eq!(lcm([7]), 7);
+        assert_eq!(lcm([]), 1);
+    }
+}
@@ -0,0 +1,79 @@
+pub mod cut;
+pub mod cycle;
+pub mod longest;
+pub mod search;
+
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+pub use cut::min_cut;
+pub use cycle::{lcm, steps_until};
+pub use longest::{longest_dag_path, longest_simple_path};
+pub use search::{astar, astar_path, components, dijkstra, distances, reachable};
+
+/// `aoc_core::trace` with the `trace` feature, and a stand-in that traces nothing without it, so
+/// the graph helpers only depend on `aoc-core` when tracing.
+mod trace {
+    #[cfg(feature = "trace")]
+    pub(crate) use aoc_core::{trace, trace::ENABLED};
+
+    #[cfg(not(feature = "trace"))]
+    pub(crate) const ENABLED: bool = false;
+
+    #[cfg(not(feature = "trace"))]
+    macro_rules! trace {
+        ($event:literal $(, $key:ident = $value:expr)* $(,)?) => {
+            if false {
+                let _ = ($event, $(&$value),*);
+            }
+        };
+    }
+
+    #[cfg(not(feature = "trace"))]
+    pub(crate) use trace;
+}
+
+/// Anything that can be a vertex of a graph.
+pub trait Node: Clone + Eq + Hash {}
+
+impl<T: Clone + Eq + Hash> Node for T {}
+
+/// The length of an edge. `Default` is the zero length.
+pub trait Weight: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Weight for T {}
+
+/// A graph in which every edge has the same length.
+pub trait Graph {
+    type Node: Node;
+
+    /// The nodes with an edge from `node`.
+    fn neighbours(&self, node: &Self::Node) -> impl IntoIterator<Item = Self::Node>;
+}
+
+/// A graph with a length on every edge.
+pub trait WeightedGraph {
+    type Node: Node;
+    type Weight: Weight;
+
+    /// The nodes with an edge from `node`, together with the length of that edge.
+    fn edges(&self, node: &Self::Node) -> impl IntoIterator<Item = (Self::Node, Self::Weight)>;
+}
+
+/// An adjacency list; nodes without an entry have no edges.
+impl<N: Node> Graph for HashMap<N, Vec<N>> {
+    type Node = N;
+
+    fn neighbours(&self, node: &N) -> impl IntoIterator<Item = N> {
+        self.get(node).into_iter().flatten().cloned()
+    }
+}
+
+/// An adjacency list with edge lengths; nodes without an entry have no edges.
+impl<N: Node, W: Weight> WeightedGraph for HashMap<N, Vec<(N, W)>> {
+    type Node = N;
+    type Weight = W;
+
+    fn edges(&self, node: &N) -> impl IntoIterator<Item = (N, W)> {
+        self.get(node).into_iter().flatten().cloned()
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use crate::{
+    trace::{trace, ENABLED as TRACING},
+    Weight, WeightedGraph,
+};
+
+/// The longest path from `start` to `end` in a graph without cycles, or `None` when `end` can't be
+/// reached.
+pub fn longest_dag_path<G: WeightedGraph>(graph: &G, start: G::Node, end: G::Node) -> Option<G::Weight> {
+    fn longest<G: WeightedGraph>(
+        graph: &G,
+        node: &G::Node,
+        end: &G::Node,
+        memo: &mut HashMap<G::Node, Option<G::Weight>>,
+    ) -> Option<G::Weight> {
+        if node == end {
+            return Some(G::Weight::default());
+        }
+        if let Some(&res) = memo.get(node) {
+            return res;
+        }
+        let res = graph
+            .edges(node)
+            .into_iter()
+            .filter_map(|(n, weight)| Some(weight + longest(graph, &n, end, memo)?))
+            .max();
+        memo.insert(node.clone(), res);
+        res
+    }
+    longest(graph, &start, &end, &mut HashMap::new())
+}
+
+/// The longest path from `start` to `end` that visits no node twice, or `None` when `end` can't be
+/// reached. This tries every path, so it is only feasible for small graphs.
+pub fn longest_simple_path<G: WeightedGraph>(graph: &G, start: G::Node, end: G::Node) -> Option<G::Weight> {
+    search_simple_paths(graph, start, end).0
+}
+
+/// The nodes a search has visited on its way so far.
+trait Visited {
+    fn contains(&self, node: usize) -> bool;
+    fn set(&mut self, node: usize, visited: bool);
+}
+
+/// Up to 128 nodes fit in a bitmask.
+impl Visited for u128 {
+    fn contains(&self, node: usize) -> bool {
+        self & (1 << node) != 0
+    }
+    fn set(&mut self, node: usize, visited: bool) {
+        *self = (*self & !(1 << node)) | ((visited as u128) << node);
+    }
+}
+
+impl Visited for Vec<bool> {
+    fn contains(&self, node: usize) -> bool {
+        self[node]
+    }
+    fn set(&mut self, node: usize, visited: bool) {
+        self[node] = visited;
+    }
+}
+
+/// Searches like `longest_simple_path`, also returning how many nodes other than `end` the search
+/// expanded.
+fn search_simple_paths<G: WeightedGraph>(graph: &G, start: G::Node, end: G::Node) -> (Option<G::Weight>, u64) {
+    // Number the reachable nodes, so the visited ones can be kept in a bitmask.
+    let mut index = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![start];
+    let mut adjacent: Vec<Vec<(usize, G::Weight)>> = Vec::new();
+    while adjacent.len() < nodes.len() {
+        let node = nodes[adjacent.len()].clone();
+        let edges = graph
+            .edges(&node)
+            .into_iter()
+            .map(|(n, weight)| {
+                let i = *index.entry(n.clone()).or_insert_with(|| {
+                    nodes.push(n);
+                    nodes.len() - 1
+                });
+                (i, weight)
+            })
+            .collect();
+        adjacent.push(edges);
+    }
+    let Some(&end) = index.get(&end) else {
+        return (None, 0);
+    };
+
+    // When only one node leads to the end, a path through it has to go to the end right away.
+    let mut into_end = (0..nodes.len()).filter(|&i| adjacent[i].iter().any(|&(n, _)| n == end));
+    if let (Some(last), None) = (into_end.next(), into_end.next()) {
+        adjacent[last].retain(|&(n, _)| n == end);
+    }
+
+    // How many nodes the search expands at each depth, and, when tracing, how many ways on it
+    // finds from them.
+    let mut frontier = vec![(0, 0); nodes.len()];
+    fn search<W: Weight>(
+        adjacent: &[Vec<(usize, W)>],
+        node: usize,
+        end: usize,
+        visited: &mut impl Visited,
+        depth: usize,
+        frontier: &mut [(u64, u64)],
+    ) -> Option<W> {
+        if node == end {
+            return Some(W::default());
+        }
+        frontier[depth].0 += 1;
+        if TRACING {
+            frontier[depth].1 += adjacent[node].iter().filter(|&&(n, _)| !visited.contains(n)).count() as u64;
+        }
+        let mut longest = None;
+        for &(n, weight) in &adjacent[node] {
+            if visited.contains(n) {
+                continue;
+            }
+            visited.set(n, true);
+            if let Some(rest) = search(adjacent, n, end, visited, depth + 1, frontier) {
+                longest = longest.max(Some(weight + rest));
+            }
+            visited.set(n, false);
+        }
+        longest
+    }
+    let longest = match nodes.len() {
+        ..=128 => search(&adjacent, 0, end, &mut 1u128, 0, &mut frontier),
+        len => {
+            let mut visited = vec![false; len];
+            visited[0] = true;
+            search(&adjacent, 0, end, &mut visited, 0, &mut frontier)
+        }
+    };
+    for (depth, &(expanded, ways)) in frontier.iter().enumerate().filter(|(_, &(expanded, _))| expanded > 0) {
+        trace!("dfs", depth = depth, expanded = expanded, frontier = ways);
+    }
+    (longest, frontier.iter().map(|&(expanded, _)| expanded).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(char, char, u32)]) -> HashMap<char, Vec<(char, u32)>> {
+        let mut graph: HashMap<char, Vec<(char, u32)>> = HashMap::new();
+        for &(a, b, weight) in edges {
+            graph.entry(a).or_default().push((b, weight));
+        }
+        graph
+    }
+
+    fn undirected(edges: &[(char, char, u32)]) -> HashMap<char, Vec<(char, u32)>> {
+        let both_ways: Vec<_> = edges.iter().flat_map(|&(a, b, w)| [(a, b, w), (b, a, w)]).collect();
+        directed(&both_ways)
+    }
+
+    #[test]
+    fn dag() {
+        let graph = directed(&[('a', 'b', 1), ('a', 'c', 2), ('b', 'd', 5), ('c', 'd', 1), ('d', 'e', 1)]);
+        assert_eq!(longest_dag_path(&graph, 'a', 'e'), Some(7));
+        assert_eq!(longest_dag_path(&graph, 'c', 'e'), Some(2));
+        assert_eq!(longest_dag_path(&graph, 'a', 'a'), Some(0));
+        assert_eq!(longest_dag_path(&graph, 'e', 'a'), None);
+    }
+
+    #[test]
+    fn simple() {
+        // a-b-d and a-c-d are short, the way over the heavy b-c edge is longest.
+        let graph = undirected(&[('a', 'b', 2), ('b', 'd', 2), ('a', 'c', 1), ('c', 'd', 1), ('b', 'c', 5)]);
+        assert_eq!(longest_simple_path(&graph, 'a', 'd'), Some(8));
+        assert_eq!(longest_simple_path(&graph, 'a', 'z'), None);
+    }
+
+    #[test]
+    fn simple_single_way_into_end() {
+        // Only c leads to e, so a path reaching c has to end there: the search expands a, a-b,
+        // a-b-c and a-c, but not a-c-b.
+        let graph = undirected(&[('a', 'b', 1), ('b', 'c', 1), ('a', 'c', 5), ('c', 'e', 1)]);
+        assert_eq!(search_simple_paths(&graph, 'a', 'e'), (Some(6), 4));
+    }
+
+    #[test]
+    fn simple_many_nodes() {
+        // A ring of 200 nodes, too many for a bitmask: the long way round is the only other way.
+        let mut graph: HashMap<u32, Vec<(u32, u32)>> = HashMap::new();
+        for n in 0..200 {
+            graph.entry(n).or_default().push(((n + 1) % 200, 1));
+            graph.entry((n + 1) % 200).or_default().push((n, 1));
+        }
+        assert_eq!(longest_simple_path(&graph, 0, 1), Some(199));
+    }
+}
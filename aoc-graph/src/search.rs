@@ -0,0 +1,177 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+};
+
+use crate::{Graph, WeightedGraph};
+
+/// Every node that can be reached from one of `starts`, including the starts themselves.
+pub fn reachable<G: Graph>(graph: &G, starts: impl IntoIterator<Item = G::Node>) -> HashSet<G::Node> {
+    let mut seen = HashSet::new();
+    let mut queue: VecDeque<G::Node> = starts.into_iter().collect();
+    while let Some(node) = queue.pop_front() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        queue.extend(graph.neighbours(&node).into_iter().filter(|n| !seen.contains(n)));
+    }
+    seen
+}
+
+/// The number of steps to every node at most `limit` steps away from the nearest of `starts`.
+pub fn distances<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    limit: usize,
+) -> HashMap<G::Node, usize> {
+    let mut dist = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !dist.contains_key(&start) {
+            dist.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, d)) = queue.pop_front() {
+        if d == limit {
+            continue;
+        }
+        for n in graph.neighbours(&node) {
+            if !dist.contains_key(&n) {
+                dist.insert(n.clone(), d + 1);
+                queue.push_back((n, d + 1));
+            }
+        }
+    }
+    dist
+}
+
+/// Splits `nodes` into groups that can reach each other. Meant for undirected graphs, where every
+/// edge is also listed the other way around.
+pub fn components<G: Graph>(graph: &G, nodes: impl IntoIterator<Item = G::Node>) -> Vec<HashSet<G::Node>> {
+    let mut seen = HashSet::new();
+    let mut components = Vec::new();
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component = reachable(graph, [node]);
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+/// The shortest distance from one of `starts` to a node matching `is_goal`, together with that
+/// node.
+pub fn dijkstra<G: WeightedGraph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<(G::Node, G::Weight)> {
+    astar(graph, starts, is_goal, |_| G::Weight::default())
+}
+
+/// Like [`dijkstra`], but explores nodes in order of distance plus `heuristic`. The heuristic may
+/// never drop by more than the length of the edge taken, and must be zero at a goal, or the result
+/// might not be the shortest.
+pub fn astar<G: WeightedGraph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl FnMut(&G::Node) -> G::Weight,
+) -> Option<(G::Node, G::Weight)> {
+    let (mut path, dist) = astar_path(graph, starts, is_goal, heuristic)?;
+    Some((path.pop().unwrap(), dist))
+}
+
+/// Like [`astar`], but returns every node on the way from the start to the goal.
+pub fn astar_path<G: WeightedGraph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> G::Weight,
+) -> Option<(Vec<G::Node>, G::Weight)> {
+    // Nodes live in `nodes` so the heap only needs to order their indices, next to the index of
+    // the node they were reached from.
+    let mut nodes: Vec<(G::Node, Option<usize>)> = Vec::new();
+    let mut best = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        let zero = G::Weight::default();
+        if best.insert(start.clone(), zero).is_none() {
+            queue.push(Reverse((heuristic(&start), zero, nodes.len())));
+            nodes.push((start, None));
+        }
+    }
+    let mut done = HashSet::new();
+    while let Some(Reverse((_, dist, i))) = queue.pop() {
+        let node = nodes[i].0.clone();
+        if is_goal(&node) {
+            let mut path: Vec<G::Node> = std::iter::successors(Some(i), |&i| nodes[i].1)
+                .map(|i| nodes[i].0.clone())
+                .collect();
+            path.reverse();
+            return Some((path, dist));
+        }
+        if !done.insert(node.clone()) {
+            continue;
+        }
+        for (n, weight) in graph.edges(&node) {
+            let new_dist = dist + weight;
+            if best.get(&n).is_some_and(|&d| d <= new_dist) {
+                continue;
+            }
+            best.insert(n.clone(), new_dist);
+            queue.push(Reverse((new_dist + heuristic(&n), new_dist, nodes.len())));
+            nodes.push((n, Some(i)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -1-> b -2-> c -1-> d, with a shortcut a -5-> c that is longer, and e on its own.
+    fn graph() -> HashMap<char, Vec<(char, u32)>> {
+        HashMap::from([
+            ('a', vec![('b', 1), ('c', 5)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 1)]),
+            ('e', vec![('d', 1)]),
+        ])
+    }
+
+    #[test]
+    fn shortest() {
+        assert_eq!(dijkstra(&graph(), ['a'], |&n| n == 'd'), Some(('d', 4)));
+        assert_eq!(dijkstra(&graph(), ['a', 'e'], |&n| n == 'd'), Some(('d', 1)));
+        assert_eq!(dijkstra(&graph(), ['d'], |&n| n == 'a'), None);
+    }
+
+    #[test]
+    fn astar_with_heuristic() {
+        // Edges left until d, which never overestimates.
+        let heuristic = |&n: &char| match n {
+            'a' => 3,
+            'b' => 2,
+            'c' => 1,
+            _ => 0,
+        };
+        assert_eq!(astar(&graph(), ['a'], |&n| n == 'd', heuristic), Some(('d', 4)));
+        assert_eq!(astar_path(&graph(), ['a'], |&n| n == 'd', heuristic), Some((vec!['a', 'b', 'c', 'd'], 4)));
+        assert_eq!(astar_path(&graph(), ['d'], |&n| n == 'd', heuristic), Some((vec!['d'], 0)));
+    }
+
+    #[test]
+    fn unweighted() {
+        let graph: HashMap<u32, Vec<u32>> = HashMap::from([(1, vec![2]), (2, vec![1, 3]), (3, vec![2]), (4, vec![5])]);
+        assert_eq!(reachable(&graph, [1]), HashSet::from([1, 2, 3]));
+        assert_eq!(distances(&graph, [1], 1), HashMap::from([(1, 0), (2, 1)]));
+        let mut sizes: Vec<usize> = components(&graph, 1..=5).iter().map(HashSet::len).collect();
+        sizes.sort();
+        assert_eq!(sizes, [2, 3]);
+    }
+}
@@ -0,0 +1,381 @@
+pub mod render;
+
+pub use linkme;
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A position in a grid: `row` counts down from the top, `col` to the right.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Default)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The point one step in `dir`, or `None` when that would go above or left of the origin.
+    pub fn step(self, dir: Direction) -> Option<Point> {
+        let (d_row, d_col) = dir.offset();
+        Some(Point::new(
+            self.row.checked_add_signed(d_row)?,
+            self.col.checked_add_signed(d_col)?,
+        ))
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self { row, col }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Change in (row, col) of one step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    /// The two directions at a right angle to this one.
+    pub fn perpendicular(self) -> [Self; 2] {
+        [self.turn_left(), self.turn_right()]
+    }
+}
+
+/// Offsets of the 8 surrounding cells, clockwise from the top left.
+const NEIGHBOURS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1)];
+
+/// A row given to [`Grid::from_rows`] that isn't as long as the first one.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RaggedRow {
+    /// Index of the row, counting from 0.
+    pub row: usize,
+    /// Number of cells in that row.
+    pub len: usize,
+    /// Number of cells in the first row.
+    pub width: usize,
+}
+
+impl Display for RaggedRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {} has {} cells, but the first row has {}", self.row + 1, self.len, self.width)
+    }
+}
+
+impl Error for RaggedRow {}
+
+/// A rectangular grid, stored row by row.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Builds a grid from equally long rows.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedRow> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((row, cells)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(RaggedRow { row, len: cells.len(), width });
+        }
+        Ok(Self { width, height, cells: rows.into_iter().flatten().collect() })
+    }
+
+    /// Parses one cell per character and one row per line.
+    pub fn parse_with(s: &str, mut f: impl FnMut(char) -> T) -> Result<Self, RaggedRow> {
+        Self::from_rows(s.lines().map(|line| line.chars().map(&mut f).collect()).collect())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.row < self.height && p.col < self.width
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p).then(|| &self.cells[p.row * self.width + p.col])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        match self.contains(p) {
+            true => Some(&mut self.cells[p.row * self.width + p.col]),
+            false => None,
+        }
+    }
+
+    /// The point at signed coordinates, or `None` outside of the grid.
+    pub fn point(&self, row: isize, col: isize) -> Option<Point> {
+        let p = Point::new(row.try_into().ok()?, col.try_into().ok()?);
+        self.contains(p).then_some(p)
+    }
+
+    /// The point at signed coordinates on a grid repeating in every direction, or `None` when the
+    /// grid is empty.
+    pub fn wrap(&self, row: isize, col: isize) -> Option<Point> {
+        Some(Point::new(
+            row.checked_rem_euclid(self.height as isize)? as usize,
+            col.checked_rem_euclid(self.width as isize)? as usize,
+        ))
+    }
+
+    /// The cell at signed coordinates on a grid repeating in every direction, or `None` when the
+    /// grid is empty.
+    pub fn get_wrapping(&self, row: isize, col: isize) -> Option<&T> {
+        self.get(self.wrap(row, col)?)
+    }
+
+    /// The point one step in `dir`, or `None` when that leaves the grid.
+    pub fn step(&self, p: Point, dir: Direction) -> Option<Point> {
+        p.step(dir).filter(|&p| self.contains(p))
+    }
+
+    /// The up to 4 points sharing a side with `p`.
+    pub fn neighbours(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.into_iter().filter_map(move |dir| self.step(p, dir))
+    }
+
+    /// The up to 8 points sharing a side or a corner with `p`.
+    pub fn neighbours_8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |(d_row, d_col)| self.point(p.row as isize + d_row, p.col as isize + d_col))
+    }
+
+    /// All points, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point::new(row, col)))
+    }
+
+    /// All points with their cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point, row by row, whose cell matches `f`.
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find_map(|(p, cell)| f(cell).then_some(p))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.width, "column {} is outside of a {}x{} grid", col, self.width, self.height);
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Mirrors the grid over its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).flat_map(|col| self.column(col).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    /// Rotates the grid a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).flat_map(|col| self.column(col).rev().cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise, so the right column becomes the top row.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).rev().flat_map(|col| self.column(col).cloned()).collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+}
+
+impl<T: From<char>> FromStr for Grid<T> {
+    type Err = RaggedRow;
+
+    fn from_str(s: &str) -> Result<Self, RaggedRow> {
+        Self::parse_with(s, T::from)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        assert!(self.contains(p), "{:?} is outside of a {}x{} grid", p, self.width, self.height);
+        &self.cells[p.row * self.width + p.col]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        assert!(self.contains(p), "{:?} is outside of a {}x{} grid", p, self.width, self.height);
+        &mut self.cells[p.row * self.width + p.col]
+    }
+}
+
+/// Writes the cells of each row next to each other, one row per line.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn ragged_rows() {
+        assert_eq!("abc\nde\nf".parse::<Grid<char>>(), Err(RaggedRow { row: 1, len: 2, width: 3 }));
+        assert_eq!(Grid::from_rows(vec![vec![1], vec![2, 3]]), Err(RaggedRow { row: 1, len: 2, width: 1 }));
+    }
+
+    #[test]
+    fn transpose() {
+        assert_eq!(grid().transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid().transpose().transpose(), grid());
+    }
+
+    #[test]
+    fn rotate() {
+        assert_eq!(grid().rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid().rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid().rotate_clockwise().rotate_counterclockwise(), grid());
+    }
+
+    #[test]
+    fn wrap() {
+        let grid = grid();
+        assert_eq!(grid.wrap(-1, -1), Some(Point::new(1, 2)));
+        assert_eq!(grid.wrap(4, 7), Some(Point::new(0, 1)));
+        assert_eq!(grid.get_wrapping(-3, 3), Some(&'d'));
+        assert_eq!(Grid::<char>::new(0, 0, '.').wrap(1, 1), None);
+        assert_eq!(Grid::<char>::new(0, 0, '.').get_wrapping(0, 0), None);
+    }
+
+    #[test]
+    fn neighbours_8_at_corners() {
+        let grid = grid();
+        let top_left: Vec<_> = grid.neighbours_8(Point::new(0, 0)).collect();
+        assert_eq!(top_left, [Point::new(0, 1), Point::new(1, 1), Point::new(1, 0)]);
+        let bottom_right: Vec<_> = grid.neighbours_8(Point::new(1, 2)).collect();
+        assert_eq!(bottom_right, [Point::new(0, 1), Point::new(0, 2), Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn step_at_origin() {
+        let origin = Point::new(0, 0);
+        assert_eq!(origin.step(Direction::Up), None);
+        assert_eq!(origin.step(Direction::Left), None);
+        assert_eq!(origin.step(Direction::Right), Some(Point::new(0, 1)));
+        assert_eq!(origin.step(Direction::Down), Some(Point::new(1, 0)));
+    }
+
+    #[test]
+    fn column() {
+        assert_eq!(grid().column(1).collect::<String>(), "be");
+        assert_eq!(grid().column(2).rev().collect::<String>(), "fc");
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of a 3x2 grid")]
+    fn column_outside() {
+        let _ = grid().column(3);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(grid().to_string(), "abc\ndef");
+        assert_eq!(Grid::new(2, 2, 0).to_string(), "00\n00");
+        assert_eq!(Grid::<char>::new(0, 0, '.').to_string(), "");
+    }
+}
@@ -0,0 +1,129 @@
+use std::{error::Error, fmt::Write, path::Path};
+
+use linkme::distributed_slice;
+
+use crate::Grid;
+
+/// A colour as red, green and blue.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const DARK: Rgb = Rgb(60, 60, 70);
+    pub const GREY: Rgb = Rgb(140, 140, 140);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(230, 50, 40);
+    pub const ORANGE: Rgb = Rgb(240, 140, 30);
+    pub const YELLOW: Rgb = Rgb(250, 220, 40);
+    pub const GREEN: Rgb = Rgb(60, 200, 80);
+    pub const BLUE: Rgb = Rgb(50, 100, 220);
+
+    /// The colour `t` of the way from this one to `other`, `t` going from 0 to 1.
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(channel(self.0, other.0), channel(self.1, other.1), channel(self.2, other.2))
+    }
+}
+
+/// A cell of a picture: the character it shows as text, in its colour.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Pixel {
+    pub ch: char,
+    pub color: Rgb,
+}
+
+impl Pixel {
+    pub const fn new(ch: char, color: Rgb) -> Self {
+        Self { ch, color }
+    }
+}
+
+/// A picture of a grid, one pixel per cell.
+pub type Frame = Grid<Pixel>;
+
+/// The frames a renderer drew, in order, or why the input didn't parse.
+pub type RenderResult = Result<Vec<Frame>, Box<dyn Error + Send + Sync>>;
+
+/// Draws what a solver works out, registered by `#[aoc(render = ...)]` on the solver.
+pub struct Renderer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub render: fn(&str) -> RenderResult,
+}
+
+#[distributed_slice]
+pub static RENDERERS: [Renderer];
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Renderer> {
+    RENDERERS.iter().find(|r| r.year == year && r.day == day && r.part == part)
+}
+
+/// Side in pixels of the square a cell takes up in an image.
+const SCALE: usize = 4;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    /// The characters only.
+    Ascii,
+    /// The characters in their colours, for a terminal.
+    Ansi,
+    /// A PPM image with a square of colour per cell.
+    Ppm,
+}
+
+impl Format {
+    /// PPM for `.ppm` files, ANSI for `.ans` files and for `-`, the terminal, plain text otherwise.
+    pub fn of(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("ppm") => Format::Ppm,
+            Some("ans") => Format::Ansi,
+            _ if path == Path::new("-") => Format::Ansi,
+            _ => Format::Ascii,
+        }
+    }
+}
+
+/// Writes `frame` in `format`.
+pub fn encode(frame: &Frame, format: Format) -> Vec<u8> {
+    match format {
+        Format::Ascii => (frame.map(|pixel| pixel.ch).to_string() + "\n").into_bytes(),
+        Format::Ansi => ansi(frame).into_bytes(),
+        Format::Ppm => ppm(frame),
+    }
+}
+
+/// Sets the colour only where it changes, and resets it at the end of every line.
+fn ansi(frame: &Frame) -> String {
+    let mut text = String::new();
+    for row in frame.rows() {
+        let mut last = None;
+        for pixel in row {
+            if last != Some(pixel.color) {
+                let Rgb(r, g, b) = pixel.color;
+                write!(text, "\x1b[38;2;{};{};{}m", r, g, b).unwrap();
+                last = Some(pixel.color);
+            }
+            text.push(pixel.ch);
+        }
+        text.push_str("\x1b[0m\n");
+    }
+    text
+}
+
+fn ppm(frame: &Frame) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", frame.width() * SCALE, frame.height() * SCALE).into_bytes();
+    for row in frame.rows() {
+        for _ in 0..SCALE {
+            for pixel in row {
+                let Rgb(r, g, b) = pixel.color;
+                for _ in 0..SCALE {
+                    image.extend([r, g, b]);
+                }
+            }
+        }
+    }
+    image
+}
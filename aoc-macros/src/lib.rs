@@ -1,17 +1,116 @@
-use darling::{FromMeta, ast::NestedMeta};
+use std::path::Path;
+
+use darling::{FromMeta, ast::NestedMeta, util::{Override, SpannedValue}};
 use proc_macro::TokenStream;
 use quote::{quote, format_ident};
-use syn::{parse_macro_input, ItemFn};
+use syn::{parenthesized, parse::{Parse, ParseStream}, parse_macro_input, punctuated::Punctuated, Expr, FnArg, ItemFn, LitStr, Meta, ReturnType, Token, Type};
 
 #[derive(FromMeta)]
 struct AocMacroArgs {
-    test: String,
-    part: Option<u8>
+    test: Option<String>,
+    /// Defaults to the year in the crate name, e.g. `aoc2022-day-05`, else `aoc_core::YEAR`.
+    year: Option<u16>,
+    /// Defaults to the number in the crate name, e.g. `day-05`.
+    day: Option<u8>,
+    /// Defaults to the number in the function name, e.g. `part_2`.
+    part: Option<u8>,
+    /// Example file for `test`, relative to the crate root.
+    file: Option<SpannedValue<String>>,
+    cases: Option<Cases>,
+    /// Value for the solver's second argument when running on the real input.
+    params: Option<Expr>,
+    /// Value for the solver's second argument when running the `test` example, defaults to `params`.
+    test_params: Option<Expr>,
+    /// Inputs a fallible parser or solver has to reject with an `Err`.
+    #[darling(multiple)]
+    malformed: Vec<String>,
+    /// Accepted answer for `input.txt`, checked whenever the input is present.
+    answer: Option<String>,
+    /// Times the solver on `input.txt` when the crate's `bench` feature is enabled.
+    bench: Option<Override<usize>>,
+    /// Function turning the input into what a solver taking `&Input` gets, defaults to `parse`.
+    parser: Option<syn::Path>,
+    /// Naive solver taking the raw input, which the solver is checked against on generated inputs.
+    reference: Option<syn::Path>,
+    /// Value for the second argument when checking against `reference`, defaults to `params`.
+    reference_params: Option<Expr>,
+    /// Function drawing what the solver works out, taking the same arguments as the solver.
+    render: Option<syn::Path>,
+}
+
+const BENCH_ITERATIONS: usize = 100;
+
+struct Case {
+    file: LitStr,
+    expected: LitStr,
+    params: Option<Expr>,
+}
+
+impl Parse for Case {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let file = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let expected = content.parse()?;
+        let mut params = None;
+        if content.parse::<Option<Token![,]>>()?.is_some() && !content.is_empty() {
+            params = Some(content.parse()?);
+            content.parse::<Option<Token![,]>>()?;
+        }
+        Ok(Case { file, expected, params })
+    }
 }
 
+struct Cases(Vec<Case>);
+
+impl FromMeta for Cases {
+    fn from_meta(item: &Meta) -> darling::Result<Self> {
+        match item {
+            Meta::List(list) => {
+                let cases = list.parse_args_with(Punctuated::<Case, Token![,]>::parse_terminated)?;
+                Ok(Cases(cases.into_iter().collect()))
+            }
+            _ => Err(darling::Error::custom("expected `cases((\"file\", \"answer\"), ...)`").with_span(item)),
+        }
+    }
+}
+
+/// Marks a solver, registers it in `aoc_core::SOLVERS` and generates tests for it. The crate using
+/// it also needs to depend on `aoc-core`.
+///
+/// A solver takes either the input as `&str`, or a reference to the parsed input, e.g.
+/// `part_1(input: &Input)`. The latter gets its input from the crate's `parse` function, which
+/// returns an `Input` or a `Result` of one; parsing and solving are timed separately. A solver
+/// taking `&[T]` gets its input from a `parse` returning `Vec<T>`. The parts of a day using the
+/// same parser share what it returned for an input, so `Input` must own its data.
+///
+/// - `day = <day>`, `part = <part>`: where the solver is registered, taken from the crate name
+///   (`day-05`) and the function name (`part_2`) when left out.
+/// - `year = <year>`: taken from the crate name (`aoc2022-day-05`) when left out, and
+///   `aoc_core::YEAR` for a crate named after the day only.
+/// - `test = "..."`: answer for `test-<part>.txt`, or `test.txt` when there is no such file.
+///   `file = "..."` points it at another example file, relative to the crate root.
+/// - `cases(("file", "answer"), ...)`: answers for several example files.
+/// - `params = ...`: extra argument for the real input, see also `test_params` and the third
+///   element of a case.
+/// - `parser = <path>`: function to parse the input with instead of `parse`.
+/// - `malformed = "..."`: input a `Result` returning parser or solver has to reject.
+/// - `answer = "..."`: answer for `input.txt`, skipped when the input is not there.
+/// - `bench` or `bench = <iterations>`: times the solver on `input.txt`, only compiled with the
+///   crate's `bench` feature: `cargo test --release --features bench bench -- --test-threads=1`.
+/// - `reference = <path>`: naive solver for the same part, taking the input as `&str` and the same
+///   extra argument. A test checks that both agree on the inputs of the crate's `#[generator]`.
+///   `reference_params = ...` replaces `params` for that check, for a naive solver too slow for
+///   the real one.
+/// - `render = <path>`: function taking the same arguments as the solver and returning the
+///   `Vec<aoc_grid::render::Frame>` it draws, which `aoc run --render` writes out. It is registered
+///   in `aoc_grid::render::RENDERERS`, so the crate also needs to depend on `aoc-grid`. A test draws
+///   the example.
 #[proc_macro_attribute]
 pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
-    let attr_args = match NestedMeta::parse_meta_list(args.into()) {
+    let attr = proc_macro2::TokenStream::from(args);
+    let attr_args = match NestedMeta::parse_meta_list(attr.clone()) {
         Ok(v) => v,
         Err(e) => { return TokenStream::from(darling::Error::from(e).write_errors()); }
     };
@@ -20,32 +119,627 @@ pub fn aoc(args: TokenStream, item: TokenStream) -> TokenStream {
         Err(e) => { return TokenStream::from(e.write_errors()); }
     };
     let item = parse_macro_input!(item as ItemFn);
-    let res = try_aoc(args, item).unwrap_or_else(|e| e.into_compile_error());
+    let res = try_aoc(args, item, &attr).unwrap_or_else(|e| e.into_compile_error());
     res.into()
 }
 
-fn try_aoc(args: AocMacroArgs, item: ItemFn) -> Result<proc_macro2::TokenStream, syn::Error> {
+/// Generates `fn main` for the crate's `#[aoc]` solvers. The binary reads `--input <path>`, stdin,
+/// `$AOC_INPUT` or `input.txt`, runs every part or only `--part <part>`, and prints timings with
+/// `--time`.
+///
+/// When the solvers live in the crate's library, pass its name, e.g. `main!(day_05)`.
+#[proc_macro]
+pub fn main(item: TokenStream) -> TokenStream {
+    let lib = match syn::parse::<Option<syn::Ident>>(item) {
+        Ok(Some(lib)) => quote!(use #lib as _;),
+        Ok(None) => quote!(),
+        Err(e) => return e.into_compile_error().into(),
+    };
+    quote!(
+        #lib
+
+        fn main() -> ::std::process::ExitCode {
+            ::aoc_core::cli::main(env!("CARGO_PKG_NAME"))
+        }
+    )
+    .into()
+}
+
+#[derive(FromMeta)]
+struct GeneratorArgs {
+    /// Defaults to the year in the crate name, e.g. `aoc2022-day-05`, else `aoc_core::YEAR`.
+    year: Option<u16>,
+    /// Defaults to the number in the crate name, e.g. `day-05`.
+    day: Option<u8>,
+    /// Size of a generated input when none is asked for.
+    size: usize,
+    /// Size of the inputs the day's solvers are tested on.
+    test_size: usize,
+}
+
+/// The year and day in the name of the crate being compiled: `day-05`, which has no year and is a
+/// day of `aoc_core::YEAR`, or `aoc2022-day-05` for another year.
+fn crate_date() -> Option<(Option<u16>, u8)> {
+    let krate = std::env::var("CARGO_PKG_NAME").ok()?;
+    let (year, day) = krate.rsplit_once("day-")?;
+    let year = match year {
+        "" => None,
+        year => Some(year.strip_prefix("aoc")?.strip_suffix('-')?.parse().ok()?),
+    };
+    Some((year, day.parse().ok()?))
+}
+
+/// The year a solver or generator is registered for, `aoc_core::YEAR` when neither `year = ...`
+/// nor the crate name gives one.
+fn year_tokens(year: Option<u16>) -> proc_macro2::TokenStream {
+    match year.or_else(|| crate_date()?.0) {
+        Some(year) => quote!(#year),
+        None => quote!(::aoc_core::YEAR),
+    }
+}
+
+/// Number of seeds a generator's inputs are tested with.
+const STRESS_SEEDS: u64 = 5;
+
+/// Marks a function generating random inputs for the crate's day, `fn(&mut Rng, usize) -> String`
+/// taking an `aoc_core::generate::Rng` and the size of the input, and registers it in
+/// `aoc_core::GENERATORS`. What the size means is up to the day, e.g. the number of lines or the
+/// width of a grid. Generated inputs have to be valid puzzle inputs, which a generated test checks
+/// by running the crate's solvers on them.
+///
+/// - `size = <size>`: size of a generated input when none is asked for, about that of a real input.
+/// - `test_size = <size>`: size of the inputs the solvers are tested on, small enough for a debug
+///   build.
+/// - `day = <day>`: taken from the crate name (`day-05`) when left out.
+/// - `year = <year>`: taken from the crate name (`aoc2022-day-05`) when left out, and
+///   `aoc_core::YEAR` for a crate named after the day only.
+#[proc_macro_attribute]
+pub fn generator(args: TokenStream, item: TokenStream) -> TokenStream {
+    let attr_args = match NestedMeta::parse_meta_list(args.into()) {
+        Ok(v) => v,
+        Err(e) => { return TokenStream::from(darling::Error::from(e).write_errors()); }
+    };
+    let args = match GeneratorArgs::from_list(&attr_args) {
+        Ok(v) => v,
+        Err(e) => { return TokenStream::from(e.write_errors()); }
+    };
+    let item = parse_macro_input!(item as ItemFn);
+    let ident = &item.sig.ident;
+    let test_ident = format_ident!("{}_tests", ident);
+    let Some(day) = args.day.or_else(|| Some(crate_date()?.1)) else {
+        return syn::Error::new_spanned(&item.sig, "#[generator] needs `day = ...` outside a `day-XX` crate")
+            .into_compile_error()
+            .into();
+    };
+    let year = year_tokens(args.year);
+    let (size, test_size) = (args.size, args.test_size);
+    quote!(
+        #item
+
+        #[::aoc_core::linkme::distributed_slice(::aoc_core::GENERATORS)]
+        #[linkme(crate = ::aoc_core::linkme)]
+        static GENERATOR: ::aoc_core::Generator = ::aoc_core::Generator {
+            year: #year,
+            day: #day,
+            krate: env!("CARGO_PKG_NAME"),
+            size: #size,
+            test_size: #test_size,
+            generate: #ident,
+        };
+
+        #[cfg(test)]
+        mod #test_ident {
+            use super::*;
+
+            #[test]
+            fn solvable() {
+                let solvers = ::aoc_core::SOLVERS.iter().filter(|s| s.krate == env!("CARGO_PKG_NAME"));
+                for seed in 0..#STRESS_SEEDS {
+                    let input = #ident(&mut ::aoc_core::generate::Rng::with_seed(seed), #test_size);
+                    for solver in solvers.clone() {
+                        // Shown when the solver panics.
+                        eprintln!("running {} on the input generated with seed {}", solver.name, seed);
+                        if let Err(e) = (solver.run)(&input).0 {
+                            panic!(
+                                "{} failed on the input generated with seed {}: {}",
+                                solver.name,
+                                seed,
+                                ::aoc_core::parse::report(&*e, &input)
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    )
+    .into()
+}
+
+/// Turns a case file name into a test name, e.g. `test-1.txt` becomes `test_1`.
+fn case_name(file: &str) -> String {
+    let stem = file.rsplit('/').next().unwrap_or(file);
+    sanitize(stem.strip_suffix(".txt").unwrap_or(stem))
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect()
+}
+
+/// The parsed input type of a solver taking `&Input`, or `None` for a solver taking `&str`. A
+/// solver taking `&[T]` gets a `Vec<T>`.
+fn parsed_input(ty: &Type) -> syn::Result<Option<Type>> {
+    let Type::Reference(reference) = ty else {
+        return Err(syn::Error::new_spanned(ty, "#[aoc] solvers take the input as `&str` or `&Input`"));
+    };
+    match reference.elem.as_ref() {
+        Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => Ok(None),
+        Type::Slice(slice) => {
+            let elem = &slice.elem;
+            Ok(Some(syn::parse_quote!(Vec<#elem>)))
+        }
+        elem => Ok(Some(elem.clone())),
+    }
+}
+
+fn returns_result(output: &ReturnType) -> bool {
+    match output {
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            Type::Path(path) => path.path.segments.last().is_some_and(|s| s.ident == "Result"),
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
+
+/// Checks that an example file exists next to the crate's manifest, so a typo is reported on the
+/// attribute instead of deep inside the generated tests.
+fn example_path(file: &str) -> Result<proc_macro2::TokenStream, String> {
+    let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") else {
+        return Ok(quote!(&::aoc_core::input::normalize(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #file)))));
+    };
+    let path = Path::new(&manifest_dir).join(file);
+    if !path.is_file() {
+        let dir = path.parent().unwrap_or(Path::new(&manifest_dir));
+        let mut found: Vec<String> = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| name.ends_with(".txt") && name != "input.txt")
+            .collect();
+        found.sort();
+        let found = match found.is_empty() {
+            true => "none".to_string(),
+            false => found.join(", "),
+        };
+        return Err(format!("example file `{}` not found in {}, found: {}", file, dir.display(), found));
+    }
+    Ok(quote!(&::aoc_core::input::normalize(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #file)))))
+}
+
+fn file_exists(file: &str) -> bool {
+    std::env::var("CARGO_MANIFEST_DIR").is_ok_and(|dir| Path::new(&dir).join(file).is_file())
+}
+
+fn try_aoc(args: AocMacroArgs, mut item: ItemFn, attr: &proc_macro2::TokenStream) -> Result<proc_macro2::TokenStream, syn::Error> {
     let ident = item.sig.ident.clone();
-    let test_value = args.test;
     let test_ident = format_ident!("{}_tests", ident);
-    let test_fn_ident = format_ident!("test_{}", ident);
-    let mut test = "../test.txt".to_string();
-    if args.part.is_some(){
-        test = format!("../test-{}.txt", args.part.unwrap());
+    let year = year_tokens(args.year);
+    let day = args.day.or_else(|| Some(crate_date()?.1));
+    let part = args.part.or_else(|| ident.to_string().strip_prefix("part_")?.parse().ok());
+
+    // A solver taking a second argument gets a `<name>_with` variant that takes it explicitly,
+    // while `<name>` itself keeps taking only the input and passes `params`.
+    let mut entry = None;
+    let solver = match (item.sig.inputs.len(), &args.params) {
+        (1, None) => ident.clone(),
+        (2, Some(params)) => {
+            let with_ident = format_ident!("{}_with", ident);
+            let vis = &item.vis;
+            let output = &item.sig.output;
+            let input = match &item.sig.inputs[0] {
+                FnArg::Typed(arg) => &arg.ty,
+                FnArg::Receiver(r) => return Err(syn::Error::new_spanned(r, "#[aoc] solvers cannot take `self`")),
+            };
+            entry = Some(quote!(
+                #vis fn #ident(inp: #input) #output {
+                    #with_ident(inp, #params)
+                }
+            ));
+            item.sig.ident = with_ident.clone();
+            with_ident
+        }
+        (2, None) => return Err(syn::Error::new_spanned(&item.sig.inputs, "#[aoc] needs `params = ...` for a solver with a second argument")),
+        (_, Some(params)) => return Err(syn::Error::new_spanned(params, "`params` needs a solver taking the input and one extra argument")),
+        _ => return Err(syn::Error::new_spanned(&item.sig.inputs, "#[aoc] solvers take the input and at most one extra argument")),
+    };
+    let call = |input: proc_macro2::TokenStream, params: Option<&Expr>| match params.or(args.params.as_ref()) {
+        Some(params) => quote!(#solver(#input, #params)),
+        None => quote!(#solver(#input)),
+    };
+    let draw = |input: proc_macro2::TokenStream, params: Option<&Expr>| {
+        let render = args.render.as_ref()?;
+        Some(match params.or(args.params.as_ref()) {
+            Some(params) => quote!(#render(#input, #params)),
+            None => quote!(#render(#input)),
+        })
+    };
+    let fallible = returns_result(&item.sig.output);
+    let name = ident.to_string();
+    let answer = |call: proc_macro2::TokenStream, file: &str| match fallible {
+        true => quote!(#call.unwrap_or_else(|e| panic!("{} returned an error on {}: {}", #name, #file, e))),
+        false => call,
+    };
+    let parsed = match &item.sig.inputs[0] {
+        FnArg::Typed(arg) => parsed_input(&arg.ty)?,
+        FnArg::Receiver(r) => return Err(syn::Error::new_spanned(r, "#[aoc] solvers cannot take `self`")),
+    };
+    let parser = args.parser.clone().unwrap_or_else(|| syn::parse_quote!(parse));
+    // Binds what the solver gets for the raw input `inp` to `input`, which has to parse in tests.
+    let prepare = |inp: proc_macro2::TokenStream, file: &str| match &parsed {
+        Some(ty) => quote!(
+            let inp = #inp;
+            let input: #ty = ::aoc_core::parse::IntoParsed::into_parsed(#parser(inp))
+                .unwrap_or_else(|e| panic!("{} could not parse {}: {}", #name, #file, e));
+        ),
+        None => quote!(let input = #inp;),
+    };
+
+    let mut tests = Vec::new();
+    let mut example = None;
+    // The extra argument the solver gets on the example.
+    let mut example_params = None;
+    if let Some(test_value) = &args.test {
+        let test_fn_ident = format_ident!("test_{}", ident);
+        let (test, raw) = match (&args.file, part) {
+            (Some(file), _) => {
+                let input = example_path(file).map_err(|e| syn::Error::new(file.span(), e))?;
+                (file.to_string(), input)
+            }
+            (None, part) => {
+                let file = match part {
+                    Some(part) if file_exists(&format!("test-{}.txt", part)) => format!("test-{}.txt", part),
+                    _ => "test.txt".to_string(),
+                };
+                let input = example_path(&file).map_err(|e| syn::Error::new_spanned(attr, e))?;
+                (file, input)
+            }
+        };
+        example = Some(test.clone());
+        example_params = args.test_params.as_ref();
+        let prepare = prepare(raw, &test);
+        let answer = answer(call(quote!(&input), args.test_params.as_ref()), &test);
+        tests.push(quote!(
+            #[test]
+            fn #test_fn_ident() {
+                #prepare
+                (&Answer(&#answer)).check(#test_value, #name, #test)
+            }
+        ));
+    }
+    let cases: Vec<_> = args.cases.iter().flat_map(|c| &c.0).collect();
+    let mut names = Vec::new();
+    for (i, case) in cases.iter().enumerate() {
+        let file = case.file.value();
+        let mut name = case_name(&file);
+        // Several cases on the same file are told apart by their answer.
+        if cases.iter().filter(|c| c.file.value() == file).count() > 1 {
+            name = format!("{}_{}", name, sanitize(&case.expected.value()));
+        }
+        if names.contains(&name) {
+            name = format!("{}_{}", name, i);
+        }
+        if syn::parse_str::<syn::Ident>(&name).is_err() {
+            name = format!("case_{}", name);
+        }
+        names.push(name.clone());
+        let test_fn_ident = format_ident!("{}", name, span = case.file.span());
+        let raw = example_path(&file).map_err(|e| syn::Error::new_spanned(&case.file, e))?;
+        if example.is_none() {
+            example = Some(file.clone());
+            example_params = case.params.as_ref();
+        }
+        let expected = &case.expected;
+        let prepare = prepare(raw, &file);
+        let answer = answer(call(quote!(&input), case.params.as_ref()), &file);
+        tests.push(quote!(
+            #[test]
+            fn #test_fn_ident() {
+                #prepare
+                (&Answer(&#answer)).check(#expected, #name, #file)
+            }
+        ));
     }
+    // Parses the real input once, before it is solved.
+    let parse_real = match &parsed {
+        Some(ty) => quote!(
+            let input: #ty = ::aoc_core::parse::IntoParsed::into_parsed(#parser(&input))
+                .unwrap_or_else(|e| panic!("{} could not parse input.txt: {}", #name, e));
+        ),
+        None => quote!(),
+    };
+    if let Some(expected) = &args.answer {
+        let answer = answer(call(quote!(&input), None), "input.txt");
+        tests.push(quote!(
+            #[test]
+            fn answer() {
+                let path = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+                let Ok(input) = ::aoc_core::input::read_file(path) else {
+                    eprintln!("skipping {}: {} not found", #name, path);
+                    return;
+                };
+                #parse_real
+                (&Answer(&#answer)).check(#expected, #name, "input.txt")
+            }
+        ));
+    }
+    if let Some(iterations) = &args.bench {
+        let iterations = iterations.clone().unwrap_or(BENCH_ITERATIONS);
+        if iterations == 0 {
+            return Err(syn::Error::new_spanned(&item.sig, "`bench` needs at least one iteration"));
+        }
+        let call = call(quote!(&input), None);
+        tests.push(quote!(
+            #[cfg(feature = "bench")]
+            #[test]
+            fn bench() {
+                use std::io::Write;
+
+                let path = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+                let Ok(input) = ::aoc_core::input::read_file(path) else {
+                    eprintln!("skipping {}: {} not found", #name, path);
+                    return;
+                };
+                #parse_real
+                let mut times = Vec::with_capacity(#iterations);
+                for _ in 0..#iterations {
+                    let start = std::time::Instant::now();
+                    std::hint::black_box(#call);
+                    times.push(start.elapsed());
+                }
+                times.sort();
+                // Written to stderr directly so it shows up without `--nocapture`.
+                writeln!(
+                    std::io::stderr(),
+                    "{}::{}: min {:?}, median {:?}, max {:?} ({} iterations)",
+                    env!("CARGO_PKG_NAME"),
+                    #name,
+                    times[0],
+                    times[times.len() / 2],
+                    times[times.len() - 1],
+                    #iterations,
+                ).unwrap();
+            }
+        ));
+    }
+    if !args.malformed.is_empty() && !fallible && parsed.is_none() {
+        return Err(syn::Error::new_spanned(&item.sig.output, "`malformed` needs a parser or solver returning a `Result`"));
+    }
+    for (i, input) in args.malformed.iter().enumerate() {
+        let test_fn_ident = match args.malformed.len() {
+            1 => format_ident!("malformed"),
+            _ => format_ident!("malformed_{}", i + 1),
+        };
+        let rejected = match (&parsed, fallible) {
+            (Some(ty), true) => {
+                let call = call(quote!(&input), None);
+                quote!(match ::aoc_core::parse::IntoParsed::<#ty>::into_parsed(#parser(#input)) {
+                    Ok(input) => #call.is_err(),
+                    Err(_) => true,
+                })
+            }
+            (Some(ty), false) => quote!(::aoc_core::parse::IntoParsed::<#ty>::into_parsed(#parser(#input)).is_err()),
+            (None, _) => {
+                let call = call(quote!(#input), None);
+                quote!(#call.is_err())
+            }
+        };
+        tests.push(quote!(
+            #[test]
+            fn #test_fn_ident() {
+                assert!(#rejected, "{} accepted malformed input {:?}", #name, #input)
+            }
+        ));
+    }
+    if let (Some(file), Some(draw)) = (&example, draw(quote!(&input), example_params)) {
+        let prepare = prepare(example_path(file).map_err(|e| syn::Error::new_spanned(attr, e))?, file);
+        tests.push(quote!(
+            #[test]
+            fn renders() {
+                #prepare
+                let frames = #draw;
+                assert!(!frames.is_empty(), "{} drew no frames of {}", #name, #file);
+            }
+        ));
+    }
+    if tests.is_empty() {
+        return Err(syn::Error::new_spanned(&item.sig, "#[aoc] needs `test = \"...\"`, `cases(...)` or `answer = \"...\"`"));
+    }
+
+    let registration = match (day, part) {
+        (Some(day), Some(part)) => {
+            // The body of a `fn(&str) -> (SolverResult, Times)` running the solver on `inp`, where
+            // `call` calls it on its input.
+            let run = |call: &dyn Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream| {
+                let solve = |input| {
+                    let call = call(input);
+                    match fallible {
+                        true => quote!(#call.map(|answer| answer.to_string()).map_err(Into::into)),
+                        false => quote!(Ok(#call.to_string())),
+                    }
+                };
+                match &parsed {
+                    Some(ty) => {
+                        let solve = solve(quote!(&input));
+                        quote!(
+                            let start = ::std::time::Instant::now();
+                            let input: Result<#ty, _> = ::aoc_core::parse::IntoParsed::into_parsed(#parser(inp));
+                            let parse = start.elapsed();
+                            let start = ::std::time::Instant::now();
+                            let answer = match input {
+                                Ok(input) => #solve,
+                                Err(e) => Err(e),
+                            };
+                            (answer, ::aoc_core::registry::Times { parse, solve: start.elapsed() })
+                        )
+                    }
+                    None => {
+                        let solve = solve(quote!(inp));
+                        quote!(
+                            let start = ::std::time::Instant::now();
+                            let answer = #solve;
+                            (answer, ::aoc_core::registry::Times { parse: Default::default(), solve: start.elapsed() })
+                        )
+                    }
+                }
+            };
+            let reference = match &args.reference {
+                Some(reference) => {
+                    let params = args.reference_params.as_ref().or(args.params.as_ref());
+                    let (naive, checked) = match params {
+                        Some(params) => (quote!(#reference(inp, #params)), run(&|input| quote!(#solver(#input, #params)))),
+                        None => (quote!(#reference(inp)), run(&|input| quote!(#solver(#input)))),
+                    };
+                    tests.push(quote!(
+                        #[test]
+                        fn matches_reference() {
+                            let generator = ::aoc_core::generate::find(#year, #day).expect("a reference needs a #[generator] for the day");
+                            let reference = ::aoc_core::registry::find(#year, #day, #part).and_then(|s| s.reference.as_ref()).unwrap();
+                            for seed in 0..#STRESS_SEEDS {
+                                let input = generator.input(Some(generator.test_size), seed);
+                                let expected = (reference.run)(&input).0.unwrap();
+                                let answer = (reference.solver)(&input).0.unwrap_or_else(|e| {
+                                    panic!("{} failed on the input generated with seed {}: {}", #name, seed, e)
+                                });
+                                assert_eq!(answer, expected, "{} disagrees with its reference on the input generated with seed {}", #name, seed);
+                            }
+                        }
+                    ));
+                    quote!(Some(::aoc_core::registry::Reference {
+                        run: {
+                            fn run(inp: &str) -> (::aoc_core::registry::SolverResult, ::aoc_core::registry::Times) {
+                                let start = ::std::time::Instant::now();
+                                let answer = Ok(#naive.to_string());
+                                (answer, ::aoc_core::registry::Times { parse: Default::default(), solve: start.elapsed() })
+                            }
+                            run
+                        },
+                        solver: {
+                            fn run(inp: &str) -> (::aoc_core::registry::SolverResult, ::aoc_core::registry::Times) {
+                                #checked
+                            }
+                            run
+                        },
+                    }))
+                }
+                None => quote!(None),
+            };
+            let input = match parsed {
+                Some(_) => quote!(&input),
+                None => quote!(inp),
+            };
+            let render = match draw(input, None) {
+                Some(draw) => {
+                    let parse = parsed.as_ref().map(|ty| {
+                        quote!(let input: #ty = ::aoc_core::parse::IntoParsed::into_parsed(#parser(inp))?;)
+                    });
+                    quote!(
+                        fn render(inp: &str) -> ::aoc_grid::render::RenderResult {
+                            #parse
+                            Ok(#draw)
+                        }
+
+                        #[::aoc_grid::linkme::distributed_slice(::aoc_grid::render::RENDERERS)]
+                        #[linkme(crate = ::aoc_grid::linkme)]
+                        static RENDERER: ::aoc_grid::render::Renderer =
+                            ::aoc_grid::render::Renderer { year: #year, day: #day, part: #part, render };
+                    )
+                }
+                None => quote!(),
+            };
+            let run = run(&|input| quote!(#ident(#input)));
+            let parsed = match &parsed {
+                Some(ty) => {
+                    let solve = match fallible {
+                        true => quote!(#ident(input).map(|answer| answer.to_string()).map_err(Into::into)),
+                        false => quote!(Ok(#ident(input).to_string())),
+                    };
+                    quote!(Some(::aoc_core::registry::Parse {
+                        parser: stringify!(#parser),
+                        parse: {
+                            fn parse_input(
+                                inp: &str,
+                            ) -> Result<::aoc_core::registry::Parsed, Box<dyn ::std::error::Error + Send + Sync>> {
+                                let input: #ty = ::aoc_core::parse::IntoParsed::into_parsed(#parser(inp))?;
+                                Ok(Box::new(input))
+                            }
+                            parse_input
+                        },
+                        solve: {
+                            fn solve_input(input: &::aoc_core::registry::Parsed) -> ::aoc_core::registry::SolverResult {
+                                let input: &#ty = input.downcast_ref().expect("solvers sharing a parser take the same input");
+                                #solve
+                            }
+                            solve_input
+                        },
+                    }))
+                }
+                None => quote!(None),
+            };
+            let example = match &example {
+                Some(example) => quote!(Some(#example)),
+                None => quote!(None),
+            };
+            let answer = match &args.answer {
+                Some(answer) => quote!(Some(#answer)),
+                None => quote!(None),
+            };
+            quote!(
+                const _: () = {
+                    fn run(inp: &str) -> (::aoc_core::registry::SolverResult, ::aoc_core::registry::Times) {
+                        #run
+                    }
+
+                    #[::aoc_core::linkme::distributed_slice(::aoc_core::SOLVERS)]
+                    #[linkme(crate = ::aoc_core::linkme)]
+                    static SOLVER: ::aoc_core::Solver = ::aoc_core::Solver {
+                        year: #year,
+                        day: #day,
+                        part: #part,
+                        name: #name,
+                        krate: env!("CARGO_PKG_NAME"),
+                        dir: env!("CARGO_MANIFEST_DIR"),
+                        example: #example,
+                        answer: #answer,
+                        run,
+                        parsed: #parsed,
+                        reference: #reference,
+                    };
+
+                    #render
+                };
+            )
+        }
+        (None, _) => return Err(syn::Error::new_spanned(&item.sig, "#[aoc] needs `day = ...` outside a `day-XX` crate")),
+        (_, None) => return Err(syn::Error::new_spanned(&item.sig, "#[aoc] needs `part = ...` for a solver not named `part_N`")),
+    };
     Ok(
         quote!(
             #item
 
+            #entry
+
+            #registration
+
             #[cfg(test)]
             mod #test_ident {
                 use super::*;
+                #[allow(unused_imports)]
+                use ::aoc_core::check::{Answer, DisplayedAnswer, ParsedAnswer};
 
-                #[test]
-                fn #test_fn_ident() {
-                    assert_eq!(#ident (include_str!(#test)).to_string(), #test_value)
-                }
+                #(#tests)*
             }
         )
     )
-} 
\ No newline at end of file
+}
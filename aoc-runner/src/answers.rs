@@ -0,0 +1,50 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use aoc_core::registry::{Puzzle, YEAR};
+
+type Inputs = BTreeMap<String, BTreeMap<String, String>>;
+
+/// Accepted answers from `answers.toml`, keyed by day, input name and solver. Days of
+/// `aoc_core::YEAR` are sections like `[day-05.input]`, those of other years go under their year,
+/// like `[2022.day-05.input]`.
+pub struct Answers(BTreeMap<Puzzle, Inputs>);
+
+/// The day of a `day-05` section.
+fn day(key: &str) -> Option<u8> {
+    key.strip_prefix("day-")?.parse().ok()
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        let invalid = |e: &dyn std::fmt::Display| format!("invalid {}: {}", path.display(), e);
+        let table: toml::Table = toml::from_str(&text).map_err(|e| invalid(&e))?;
+        let mut answers = BTreeMap::new();
+        for (key, value) in table {
+            match (day(&key), key.parse::<u16>()) {
+                (Some(day), _) => {
+                    let inputs = value.try_into().map_err(|e| invalid(&e))?;
+                    answers.insert(Puzzle { year: YEAR, day }, inputs);
+                }
+                (_, Ok(year)) => {
+                    let days: BTreeMap<String, Inputs> = value.try_into().map_err(|e| invalid(&e))?;
+                    for (key, inputs) in days {
+                        let day = day(&key).ok_or_else(|| invalid(&format!("`{}.{}` is not a day", year, key)))?;
+                        answers.insert(Puzzle { year, day }, inputs);
+                    }
+                }
+                _ => return Err(invalid(&format!("`{}` is neither a day nor a year", key))),
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    /// Names of the inputs with recorded answers for `puzzle`.
+    pub fn inputs(&self, puzzle: Puzzle) -> impl Iterator<Item = &str> {
+        self.0.get(&puzzle).into_iter().flat_map(|inputs| inputs.keys().map(String::as_str))
+    }
+
+    pub fn get(&self, puzzle: Puzzle, input: &str, solver: &str) -> Option<&str> {
+        self.0.get(&puzzle)?.get(input)?.get(solver).map(String::as_str)
+    }
+}
@@ -0,0 +1,300 @@
+use std::{
+    fs,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    generate,
+    input::read_default,
+    registry::{Puzzle, Solver},
+};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::exec::{execute, Job, Outcome};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// CSV for `.csv` files, JSON otherwise.
+    pub fn of(path: &Path) -> Self {
+        match path.extension().is_some_and(|ext| ext == "csv") {
+            true => Format::Csv,
+            false => Format::Json,
+        }
+    }
+}
+
+/// Timings of one solver on its input. Times are medians over all runs; parsing covers reading and
+/// normalizing the input, and the day's own parsing for solvers taking parsed input.
+#[derive(Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak_bytes: u64,
+}
+
+impl Entry {
+    fn total(&self) -> Duration {
+        Duration::from_nanos(self.parse_ns + self.solve_ns)
+    }
+
+    fn puzzle(&self) -> Puzzle {
+        Puzzle { year: self.year, day: self.day }
+    }
+}
+
+const CSV_HEADER: &str = "year,day,part,answer,parse_ns,solve_ns,allocations,bytes,peak_bytes";
+
+fn median(mut times: Vec<Duration>) -> u64 {
+    times.sort();
+    times[times.len() / 2].as_nanos() as u64
+}
+
+/// Where the solvers get their input from.
+pub enum Source {
+    /// The day's `input.txt`.
+    Real,
+    /// An input from the day's generator, of its default size when there is none.
+    Generated { size: Option<usize>, seed: u64 },
+}
+
+/// Runs every solver `runs` times on its input from `source`, one run at a time on a solver thread
+/// like `aoc run` does. A part that fails, panics or runs longer than `timeout` is reported and
+/// left out. Returns the entries of the other parts, and whether there were no such failures.
+pub fn bench(
+    solvers: &[&'static Solver],
+    runs: usize,
+    source: &Source,
+    timeout: Option<Duration>,
+) -> Result<(Vec<Entry>, bool), String> {
+    if runs == 0 {
+        return Err("--runs needs at least one run".to_string());
+    }
+    let mut report = Vec::new();
+    let mut ok = true;
+    for &solver in solvers {
+        let generated = match *source {
+            Source::Real => None,
+            Source::Generated { size, seed } => match generate::find(solver.year, solver.day) {
+                Some(generator) => Some(generator.input(size, seed)),
+                None => return Err(format!("{} has no generator", solver.puzzle())),
+            },
+        };
+        let (mut parse, mut solve) = (Vec::new(), Vec::new());
+        let mut last = None;
+        let mut failure = None;
+        for _ in 0..runs {
+            let start = Instant::now();
+            let input: Arc<str> = match &generated {
+                Some(input) => input.as_str().into(),
+                None => read_default(solver.dir)?.into(),
+            };
+            let read = start.elapsed();
+            execute(&[Job::new(solver, input)], 1, timeout, |_, outcome| match outcome {
+                Outcome::Done(Ok(answer), times, stats) => {
+                    parse.push(read + times.parse);
+                    solve.push(times.solve);
+                    last = Some((answer, stats));
+                }
+                outcome => failure = Some(outcome),
+            });
+            if failure.is_some() {
+                break;
+            }
+        }
+        let (Some((answer, stats)), None) = (last, &failure) else {
+            eprintln!("{} part {}: {}", solver.puzzle(), solver.part, failure.unwrap());
+            ok = false;
+            continue;
+        };
+        let entry = Entry {
+            year: solver.year,
+            day: solver.day,
+            part: solver.part,
+            answer,
+            parse_ns: median(parse),
+            solve_ns: median(solve),
+            allocations: stats.allocations,
+            bytes: stats.bytes,
+            peak_bytes: stats.peak_bytes,
+        };
+        eprintln!("{} part {}: {:.2?}", entry.puzzle(), entry.part, entry.total());
+        report.push(entry);
+    }
+    Ok((report, ok))
+}
+
+/// `field` as a CSV field, quoted when it holds a comma, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// The records of a CSV text, each a list of its fields, unquoting the quoted ones.
+fn csv_records(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                record.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(String::new()),
+            '\r' if !quoted => {}
+            '\n' if !quoted => records.push(std::mem::replace(&mut record, vec![String::new()])),
+            c => record.last_mut().unwrap().push(c),
+        }
+    }
+    if record != [""] {
+        records.push(record);
+    }
+    records
+}
+
+pub fn write(report: &[Entry], format: Format) -> String {
+    match format {
+        Format::Json => serde_json::to_string_pretty(report).unwrap() + "\n",
+        Format::Csv => {
+            let mut csv = format!("{}\n", CSV_HEADER);
+            for e in report {
+                csv += &format!(
+                    "{},{},{},{},{},{},{},{},{}\n",
+                    e.year,
+                    e.day,
+                    e.part,
+                    csv_field(&e.answer),
+                    e.parse_ns,
+                    e.solve_ns,
+                    e.allocations,
+                    e.bytes,
+                    e.peak_bytes
+                );
+            }
+            csv
+        }
+    }
+}
+
+/// Reads a CSV report by the names in its header.
+fn parse_csv(path: &Path, text: &str) -> Result<Vec<Entry>, String> {
+    let mut records = csv_records(text).into_iter();
+    let header = records.next().unwrap_or_default();
+    let column = |name| header.iter().position(|column| column == name);
+    let required = |name| column(name).ok_or(format!("{} has no {} column", path.display(), name));
+    let (year, day, part) = (required("year")?, required("day")?, required("part")?);
+    let answer = required("answer")?;
+    let (parse_ns, solve_ns) = (required("parse_ns")?, required("solve_ns")?);
+    let (allocations, bytes, peak_bytes) = (required("allocations")?, required("bytes")?, required("peak_bytes")?);
+    let entry = |fields: Vec<String>| {
+        let number = |i: usize| fields.get(i)?.parse().ok();
+        Some(Entry {
+            year: fields.get(year)?.parse().ok()?,
+            day: fields.get(day)?.parse().ok()?,
+            part: fields.get(part)?.parse().ok()?,
+            answer: fields.get(answer)?.clone(),
+            parse_ns: number(parse_ns)?,
+            solve_ns: number(solve_ns)?,
+            allocations: number(allocations)?,
+            bytes: number(bytes)?,
+            peak_bytes: number(peak_bytes)?,
+        })
+    };
+    records
+        .enumerate()
+        .map(|(i, fields)| entry(fields).ok_or(format!("invalid {} row {}", path.display(), i + 2)))
+        .collect()
+}
+
+pub fn read(path: &Path) -> Result<Vec<Entry>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    match Format::of(path) {
+        Format::Json => serde_json::from_str(&text).map_err(|e| format!("invalid {}: {}", path.display(), e)),
+        Format::Csv => parse_csv(path, &text),
+    }
+}
+
+/// Prints how `report` compares with `baseline` and returns whether no solver got slower or made
+/// more allocations by more than `threshold` percent, or changed its answer. Solvers faster than
+/// `min` in the baseline are not checked for slowdowns, their timings being mostly noise.
+pub fn compare(report: &[Entry], baseline: &[Entry], threshold: f64, min: Duration) -> bool {
+    let mut ok = true;
+    for entry in report {
+        let Some(base) = baseline.iter().find(|b| b.puzzle() == entry.puzzle() && b.part == entry.part) else {
+            eprintln!("{} part {}: not in baseline", entry.puzzle(), entry.part);
+            continue;
+        };
+        let change = (entry.total().as_secs_f64() / base.total().as_secs_f64() - 1.0) * 100.0;
+        // A baseline without allocation counts, from a runner built without them, can't regress.
+        let allocations = (base.allocations > 0)
+            .then(|| (entry.allocations as f64 / base.allocations as f64 - 1.0) * 100.0);
+        let verdict = if entry.answer != base.answer {
+            ok = false;
+            format!("  ANSWER CHANGED from {}", base.answer)
+        } else if change > threshold && base.total() >= min {
+            ok = false;
+            "  REGRESSED".to_string()
+        } else if allocations.is_some_and(|change| change > threshold) {
+            ok = false;
+            "  MORE ALLOCATIONS".to_string()
+        } else {
+            String::new()
+        };
+        eprintln!(
+            "{} part {}: {:.2?} -> {:.2?} ({:+.1}%), {} -> {} allocations{}",
+            entry.puzzle(),
+            entry.part,
+            base.total(),
+            entry.total(),
+            change,
+            base.allocations,
+            entry.allocations,
+            verdict
+        );
+    }
+    ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_round_trip() {
+        let entry = |answer: &str| Entry {
+            year: 2022,
+            day: 5,
+            part: 1,
+            answer: answer.to_string(),
+            parse_ns: 1,
+            solve_ns: 2,
+            allocations: 3,
+            bytes: 4,
+            peak_bytes: 5,
+        };
+        let report = [entry("CMZ"), entry("1,2"), entry("say \"hi\""), entry("#..\n.#.")];
+        let csv = write(&report, Format::Csv);
+        let read = parse_csv(Path::new("report.csv"), &csv).unwrap();
+        let answers: Vec<&str> = read.iter().map(|e| e.answer.as_str()).collect();
+        assert_eq!(answers, ["CMZ", "1,2", "say \"hi\"", "#..\n.#."]);
+        assert!(read.iter().all(|e| (e.year, e.peak_bytes) == (2022, 5)));
+    }
+}
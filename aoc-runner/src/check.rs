@@ -0,0 +1,117 @@
+use std::{path::Path, sync::Arc, time::Duration};
+
+use aoc_core::{input::read_file, registry::Solver};
+
+use crate::{
+    answers::Answers,
+    exec::{execute, Job, Outcome},
+};
+
+enum Status {
+    Pass,
+    Fail { expected: String },
+    New,
+    Error(String),
+}
+
+struct Row<'a> {
+    solver: &'a Solver,
+    input: String,
+    answer: String,
+    status: Status,
+}
+
+/// The accepted answer of `solver` for the input `name`: the one recorded in `answers.toml`, and
+/// for `input` the one pinned with `#[aoc(answer = ...)]` as well, which have to agree.
+fn expected<'a>(answers: &'a Answers, solver: &Solver, name: &str) -> Result<Option<&'a str>, String> {
+    let recorded = answers.get(solver.puzzle(), name, solver.name);
+    let pinned = solver.answer.filter(|_| name == "input");
+    match (recorded, pinned) {
+        (Some(recorded), Some(pinned)) if recorded != pinned => {
+            Err(format!("FAIL, answers.toml has {} but #[aoc] pins {}", recorded, pinned))
+        }
+        (recorded, pinned) => Ok(recorded.or(pinned)),
+    }
+}
+
+/// Runs `solvers` on every input with recorded answers, and on `input.txt` when it exists, and
+/// prints how each answer compares. Returns whether all recorded answers matched.
+pub fn check(solvers: &[&'static Solver], answers: &Answers, threads: usize, timeout: Option<Duration>) -> bool {
+    let mut rows = Vec::new();
+    let mut jobs = Vec::new();
+    let mut names = Vec::new();
+    for day in solvers.chunk_by(|a, b| a.puzzle() == b.puzzle()) {
+        let dir = Path::new(day[0].dir);
+        let mut inputs: Vec<&str> = answers.inputs(day[0].puzzle()).collect();
+        if !inputs.contains(&"input") && dir.join("input.txt").is_file() {
+            inputs.insert(0, "input");
+        }
+        for name in inputs {
+            match read_file(dir.join(format!("{}.txt", name))) {
+                Ok(input) => {
+                    let input: Arc<str> = input.into();
+                    for &solver in day {
+                        jobs.push(Job::new(solver, input.clone()));
+                        names.push(name.to_string());
+                    }
+                }
+                Err(e) => rows.extend(day.iter().map(|&solver| Row {
+                    solver,
+                    input: name.to_string(),
+                    answer: "-".to_string(),
+                    status: Status::Error(format!("ERROR: {}", e)),
+                })),
+            }
+        }
+    }
+    let mut names = names.into_iter();
+    execute(&jobs, threads, timeout, |job, outcome| {
+        let solver = job.solver;
+        let name = names.next().unwrap();
+        let (answer, status) = match (outcome, expected(answers, solver, &name)) {
+            (Outcome::Done(Ok(answer), ..), Err(e)) => (answer, Status::Error(e)),
+            (Outcome::Done(Ok(answer), ..), Ok(None)) => (answer, Status::New),
+            (Outcome::Done(Ok(answer), ..), Ok(Some(expected))) if answer == expected => (answer, Status::Pass),
+            (Outcome::Done(Ok(answer), ..), Ok(Some(expected))) => {
+                (answer, Status::Fail { expected: expected.to_string() })
+            }
+            (outcome, _) => ("-".to_string(), Status::Error(outcome.to_string())),
+        };
+        rows.push(Row { solver, input: name, answer, status });
+    });
+    rows.sort_by_key(|row| row.solver.puzzle());
+    print(&rows)
+}
+
+fn print(rows: &[Row]) -> bool {
+    let input_width = rows.iter().map(|r| r.input.len()).chain([5]).max().unwrap();
+    let answer_width = rows.iter().map(|r| r.answer.len()).chain([6]).max().unwrap();
+    println!("year  day  part  {:input_width$}  {:answer_width$}  status", "input", "answer");
+    let (mut pass, mut fail, mut new) = (0, 0, 0);
+    for row in rows {
+        let status = match &row.status {
+            Status::Pass => {
+                pass += 1;
+                "pass".to_string()
+            }
+            Status::Fail { expected } => {
+                fail += 1;
+                format!("FAIL, expected {}", expected)
+            }
+            Status::New => {
+                new += 1;
+                "new".to_string()
+            }
+            Status::Error(e) => {
+                fail += 1;
+                e.clone()
+            }
+        };
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:input_width$}  {:answer_width$}  {}",
+            row.solver.year, row.solver.day, row.solver.part, row.input, row.answer, status
+        );
+    }
+    println!("{} passed, {} failed, {} new", pass, fail, new);
+    fail == 0
+}
@@ -0,0 +1,27 @@
+//! Links every day so its solvers end up in `aoc_core::SOLVERS`. Written by `aoc new`.
+
+use day_01 as _;
+use day_02 as _;
+use day_03 as _;
+use day_04 as _;
+use day_05 as _;
+use day_06 as _;
+use day_07 as _;
+use day_08 as _;
+use day_09 as _;
+use day_10 as _;
+use day_11 as _;
+use day_12 as _;
+use day_13 as _;
+use day_14 as _;
+use day_15 as _;
+use day_16 as _;
+use day_17 as _;
+use day_18 as _;
+use day_19 as _;
+use day_20 as _;
+use day_21 as _;
+use day_22 as _;
+use day_23 as _;
+use day_24 as _;
+use day_25 as _;
@@ -0,0 +1,154 @@
+use std::{sync::Arc, time::Duration};
+
+use aoc_core::{
+    generate,
+    registry::{Reference, Solver},
+};
+
+use crate::exec::{execute, Job, Outcome};
+
+/// A solver checked against its reference on one generated input.
+struct Run {
+    solver: &'static Solver,
+    seed: u64,
+    size: usize,
+}
+
+/// The answer of a reference, or `None` when it failed too, in which case there is nothing to
+/// compare with.
+fn expected(outcome: &Outcome) -> Option<&str> {
+    match outcome {
+        Outcome::Done(Ok(answer), ..) => Some(answer),
+        _ => None,
+    }
+}
+
+fn agree(solver: &Outcome, reference: &Outcome) -> bool {
+    matches!((solver, expected(reference)), (Outcome::Done(Ok(answer), ..), Some(expected)) if answer == expected)
+}
+
+fn elapsed(outcome: &Outcome) -> Duration {
+    match outcome {
+        Outcome::Done(_, times, _) => times.total(),
+        Outcome::TimedOut(timeout) => *timeout,
+        Outcome::Panicked(_) => Duration::ZERO,
+    }
+}
+
+/// Runs a solver and its reference on `input` at the same time.
+fn run_both(solver: &'static Solver, input: &str, timeout: Option<Duration>) -> (Outcome, Outcome) {
+    let input: Arc<str> = input.into();
+    let reference = solver.reference.as_ref().unwrap();
+    let jobs = [reference.solver, reference.run].map(|run| Job { solver, run: Some(run), input: input.clone() });
+    let mut outcomes = Vec::new();
+    execute(&jobs, 2, timeout, |_, outcome| outcomes.push(outcome));
+    let reference = outcomes.pop().unwrap();
+    (outcomes.pop().unwrap(), reference)
+}
+
+/// Shrinks an input the solver gets wrong while the reference still answers it: first by
+/// generating it at smaller sizes with the same seed, then by dropping ever smaller chunks of
+/// lines. When the solver gave a wrong answer rather than failing, it has to keep answering, so
+/// the input doesn't shrink into one the solver rightly rejects.
+fn minimize(solver: &'static Solver, run: &Run, input: String, answered: bool, timeout: Option<Duration>) -> String {
+    let generator = generate::find(solver.year, solver.day).unwrap();
+    let wrong = |input: &str| {
+        let (solver, reference) = run_both(solver, input, timeout);
+        let failed = !matches!(solver, Outcome::Done(Ok(_), ..));
+        expected(&reference).is_some() && !agree(&solver, &reference) && !(answered && failed)
+    };
+    let mut input = input;
+    let mut size = run.size / 2;
+    while size > 0 {
+        let smaller = generator.input(Some(size), run.seed);
+        if !wrong(&smaller) {
+            break;
+        }
+        input = smaller;
+        size /= 2;
+    }
+    let mut lines: Vec<&str> = input.lines().collect();
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < lines.len() {
+            let end = (start + chunk).min(lines.len());
+            let candidate = [&lines[..start], &lines[end..]].concat();
+            match !candidate.is_empty() && wrong(&candidate.join("\n")) {
+                true => lines = candidate,
+                false => start = end,
+            }
+        }
+        chunk /= 2;
+    }
+    lines.join("\n")
+}
+
+/// Runs the solvers among `solvers` that have a reference, and the references, on inputs generated
+/// with `seeds` consecutive seeds from `first`. For each solver the first input it disagrees with
+/// its reference on is printed, shrunk as far as the disagreement persists. Days without a
+/// generator are skipped. Returns whether every solver agreed with its reference.
+pub fn diff(
+    solvers: &[&'static Solver],
+    size: Option<usize>,
+    first: u64,
+    seeds: u64,
+    threads: usize,
+    timeout: Option<Duration>,
+) -> Result<bool, String> {
+    let mut jobs = Vec::new();
+    let mut runs = Vec::new();
+    for day in solvers.chunk_by(|a, b| a.puzzle() == b.puzzle()) {
+        let checked: Vec<(&'static Solver, &Reference)> =
+            day.iter().filter_map(|&s| Some((s, s.reference.as_ref()?))).collect();
+        if checked.is_empty() {
+            continue;
+        }
+        let Some(generator) = generate::find(day[0].year, day[0].day) else {
+            eprintln!("{}: no generator, skipped", day[0].puzzle());
+            continue;
+        };
+        let size = size.unwrap_or(generator.test_size);
+        for seed in first..first + seeds {
+            let input: Arc<str> = generator.input(Some(size), seed).into();
+            for &(solver, reference) in &checked {
+                for run in [reference.solver, reference.run] {
+                    jobs.push(Job { solver, run: Some(run), input: input.clone() });
+                }
+                runs.push(Run { solver, seed, size });
+            }
+        }
+    }
+    if jobs.is_empty() {
+        return Err("no solvers with a reference match".to_string());
+    }
+    let mut outcomes = Vec::new();
+    execute(&jobs, threads, timeout, |_, outcome| outcomes.push(outcome));
+    let mut outcomes = outcomes.into_iter();
+    let mut disagreed: Vec<&'static Solver> = Vec::new();
+    for (run, job) in runs.iter().zip(jobs.chunks(2)) {
+        let (solver, reference) = (outcomes.next().unwrap(), outcomes.next().unwrap());
+        if agree(&solver, &reference) || disagreed.iter().any(|s| std::ptr::eq(*s, run.solver)) {
+            continue;
+        }
+        disagreed.push(run.solver);
+        eprintln!(
+            "{} part {}, size {}, seed {}: solver {}, reference {}",
+            run.solver.puzzle(), run.solver.part, run.size, run.seed, solver, reference
+        );
+        if expected(&reference).is_none() {
+            continue;
+        }
+        // Candidates get ten times as long as the input that showed the disagreement.
+        let slowest = elapsed(&solver).max(elapsed(&reference)) * 10;
+        let limit = slowest.max(Duration::from_secs(1));
+        let answered = matches!(solver, Outcome::Done(Ok(_), ..));
+        let limit = Some(timeout.map_or(limit, |t| t.min(limit)));
+        let shrunk = minimize(run.solver, run, job[0].input.to_string(), answered, limit);
+        let (solver, reference) = run_both(run.solver, &shrunk, timeout);
+        eprintln!("Shrunk to {} lines, solver {}, reference {}:", shrunk.lines().count(), solver, reference);
+        eprintln!("{}", shrunk);
+    }
+    println!("{} runs, {} solvers disagreed with their reference", runs.len(), disagreed.len());
+    Ok(disagreed.is_empty())
+}
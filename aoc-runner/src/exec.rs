@@ -0,0 +1,149 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Once},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    registry::{ParsedInputs, Run, Solver, SolverResult, Times},
+    trace,
+};
+
+use crate::alloc::{measure, AllocStats};
+
+/// Some solvers recurse deeply, so they get more than the 2 MiB a spawned thread has by default.
+const STACK_SIZE: usize = 64 << 20;
+
+pub struct Job {
+    pub solver: &'static Solver,
+    /// What runs on the input instead of the solver, when it is checked against its reference.
+    pub run: Option<Run>,
+    pub input: Arc<str>,
+}
+
+impl Job {
+    pub fn new(solver: &'static Solver, input: Arc<str>) -> Self {
+        Self { solver, run: None, input }
+    }
+}
+
+pub enum Outcome {
+    /// The solver returned, after taking `Times` and allocating what `AllocStats` counted.
+    Done(SolverResult, Times, AllocStats),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Done(Ok(answer), ..) => write!(f, "{}", answer),
+            Outcome::Done(Err(e), ..) => write!(f, "ERROR: {}", e),
+            Outcome::Panicked(message) => write!(f, "PANIC: {}", message),
+            Outcome::TimedOut(timeout) => write!(f, "TIMEOUT after {:.2?}", timeout),
+        }
+    }
+}
+
+thread_local! {
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Keeps panic messages of solver threads for their report instead of printing them in between
+/// other results.
+fn capture_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some("solver") {
+                return default(info);
+            }
+            let message = match (info.payload().downcast_ref::<&str>(), info.payload().downcast_ref::<String>()) {
+                (Some(s), _) => s.to_string(),
+                (_, Some(s)) => s.clone(),
+                _ => "unknown panic".to_string(),
+            };
+            let message = match info.location() {
+                Some(location) => format!("{} at {}", message, location),
+                None => message,
+            };
+            PANIC.with(|panic| *panic.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+fn spawn(index: usize, job: &Job, parsed: Arc<ParsedInputs>, results: mpsc::Sender<(usize, Outcome)>) {
+    let (solver, run) = (job.solver, job.run);
+    let (year, day, part) = (solver.year, solver.day, solver.part);
+    let input = job.input.clone();
+    thread::Builder::new()
+        .name("solver".to_string())
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let run = || match run {
+                Some(run) => run(&input),
+                None => solver.run_on(&input, &parsed),
+            };
+            let solve = || measure(|| trace::scope(year, day, part, run));
+            let outcome = match panic::catch_unwind(AssertUnwindSafe(solve)) {
+                Ok(((result, times), allocs)) => Outcome::Done(result, times, allocs),
+                Err(_) => Outcome::Panicked(PANIC.with(|panic| panic.borrow_mut().take()).unwrap_or_default()),
+            };
+            // Fails once the run is over, in which case nobody waits for a timed out solver.
+            let _ = results.send((index, outcome));
+        })
+        .expect("could not spawn a solver thread");
+}
+
+/// Runs `jobs` on up to `threads` threads at once and calls `report` with each outcome in the
+/// order of `jobs`. A solver running longer than `timeout` is reported as timed out and left
+/// running in the background, since a thread can't be stopped from the outside. Jobs sharing an
+/// input parse it once per day, and the allocations and trace of that parse go to whichever part
+/// parses it.
+pub fn execute(jobs: &[Job], threads: usize, timeout: Option<Duration>, mut report: impl FnMut(&Job, Outcome)) {
+    capture_panics();
+    let mut inputs: HashMap<*const u8, Arc<ParsedInputs>> = HashMap::new();
+    let parsed: Vec<_> = jobs.iter().map(|job| inputs.entry(job.input.as_ptr()).or_default().clone()).collect();
+    let (sender, receiver) = mpsc::channel();
+    let mut next = 0;
+    let mut running = BTreeMap::new();
+    let mut finished = BTreeMap::new();
+    let mut reported = 0;
+    while reported < jobs.len() {
+        while running.len() < threads.max(1) && next < jobs.len() {
+            spawn(next, &jobs[next], parsed[next].clone(), sender.clone());
+            running.insert(next, timeout.and_then(|timeout| Instant::now().checked_add(timeout)));
+            next += 1;
+        }
+        let received = match running.values().flatten().min() {
+            Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())).ok(),
+            None => receiver.recv().ok(),
+        };
+        match received {
+            Some((index, outcome)) => {
+                if running.remove(&index).is_some() {
+                    finished.insert(index, outcome);
+                }
+            }
+            None => {
+                let now = Instant::now();
+                running.retain(|&index, deadline| match deadline {
+                    Some(deadline) if *deadline <= now => {
+                        finished.insert(index, Outcome::TimedOut(timeout.unwrap_or_default()));
+                        false
+                    }
+                    _ => true,
+                });
+            }
+        }
+        while let Some(outcome) = finished.remove(&reported) {
+            report(&jobs[reported], outcome);
+            reported += 1;
+        }
+    }
+}
@@ -0,0 +1,454 @@
+use std::{fs, io, path::PathBuf, process::ExitCode, sync::Arc, thread, time::Duration};
+
+use aoc_core::{
+    input::{read_default, resolve},
+    parse::report,
+    registry::{solvers, Puzzle, Solver, YEAR},
+};
+use clap::{Args, Parser, Subcommand};
+
+mod alloc;
+mod answers;
+mod bench;
+mod check;
+mod days;
+mod diff;
+mod exec;
+mod render;
+mod scaffold;
+mod stress;
+
+use answers::Answers;
+use exec::{execute, Job, Outcome};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solvers of a day, or of every day
+    Run(RunArgs),
+    /// Compares the answers of a day, or of every day, with answers.toml
+    Check(CheckArgs),
+    /// Times the solvers of a day, or of every day, on their input.txt
+    Bench(BenchArgs),
+    /// Prints a random input for a day
+    Generate(GenerateArgs),
+    /// Runs the solvers of a day, or of every day, on many random inputs
+    Stress(StressArgs),
+    /// Checks the solvers of a day, or of every day, against naive solvers on small random inputs
+    Diff(DiffArgs),
+    /// Creates the crate for a new day and adds it to the workspace and the runner
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Days to run: `all`, a year, a day, or `<year>/<day>` with an optional `/<part>`
+    #[arg(value_parser = parse_day)]
+    day: Days,
+    /// Only run this part
+    #[arg(long, short)]
+    part: Option<u8>,
+    /// Input file, `-` for stdin. Without it a single day reads piped stdin, then $AOC_INPUT,
+    /// then the day's input.txt
+    #[arg(long, short)]
+    input: Option<String>,
+    /// Print how long each part took
+    #[arg(long, short)]
+    time: bool,
+    /// Print how much each part allocated; needs the default `count-allocs` feature
+    #[arg(long)]
+    allocs: bool,
+    /// Draw what the parts work out to this file, `-` for the terminal: an image for `.ppm` files,
+    /// coloured text for `.ans` files and plain text otherwise
+    #[arg(long)]
+    render: Option<PathBuf>,
+    /// Write every frame drawn to a numbered file, not only the last one
+    #[arg(long, requires = "render")]
+    frames: bool,
+    /// Write the events the solvers trace to this file as JSON lines; needs the `trace` feature
+    #[arg(long)]
+    trace: Option<PathBuf>,
+    #[command(flatten)]
+    jobs: JobArgs,
+}
+
+#[derive(Args)]
+struct JobArgs {
+    /// Number of solvers to run at once, by default one per core
+    #[arg(long, short)]
+    jobs: Option<usize>,
+    /// Seconds after which a solver is reported as timed out, 0 to wait forever
+    #[arg(long, default_value_t = 60.0)]
+    timeout: f64,
+}
+
+impl JobArgs {
+    fn threads(&self) -> usize {
+        self.jobs
+            .or(thread::available_parallelism().ok().map(Into::into))
+            .unwrap_or(1)
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        (self.timeout > 0.0).then(|| Duration::from_secs_f64(self.timeout))
+    }
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Days to check: `all`, a year, a day, or `<year>/<day>` with an optional `/<part>`
+    #[arg(value_parser = parse_day, default_value = "all")]
+    day: Days,
+    /// Answers file to compare with
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml"))]
+    answers: PathBuf,
+    #[command(flatten)]
+    jobs: JobArgs,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Days to time: `all`, a year, a day, or `<year>/<day>` with an optional `/<part>`
+    #[arg(value_parser = parse_day, default_value = "all")]
+    day: Days,
+    /// Number of runs per part
+    #[arg(long, default_value_t = 3)]
+    runs: usize,
+    /// Report format, by default taken from the --output extension, else JSON
+    #[arg(long, value_enum)]
+    format: Option<bench::Format>,
+    /// File to write the report to instead of stdout
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+    /// Earlier report to compare with, failing when a part got slower or changed its answer
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Slowdown in percent that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+    /// Parts faster than this many milliseconds in the baseline are not checked for slowdowns
+    #[arg(long, default_value_t = 1.0)]
+    min_ms: f64,
+    /// Time the solvers on a generated input instead of input.txt
+    #[arg(long)]
+    generated: bool,
+    /// Size of the generated input, by default about that of a real input
+    #[arg(long, requires = "generated")]
+    size: Option<usize>,
+    /// Seed of the generated input
+    #[arg(long, default_value_t = 0, requires = "generated")]
+    seed: u64,
+    /// Seconds after which a run is reported as timed out, 0 to wait forever
+    #[arg(long, default_value_t = 60.0)]
+    timeout: f64,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for, e.g. `5` or `2022/05`
+    #[arg(value_parser = parse_day)]
+    day: Days,
+    /// Size of the input, by default about that of a real input; what it counts depends on the day
+    #[arg(long, short)]
+    size: Option<usize>,
+    /// Seed of the random input, the same seed and size giving the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// File to write the input to instead of stdout
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct StressArgs {
+    /// Days to test: `all`, a year, a day, or `<year>/<day>` with an optional `/<part>`
+    #[arg(value_parser = parse_day, default_value = "all")]
+    day: Days,
+    /// Size of the inputs, by default about that of a real input
+    #[arg(long, short)]
+    size: Option<usize>,
+    /// Number of inputs per day
+    #[arg(long, default_value_t = 10)]
+    seeds: u64,
+    /// Seed of the first input, the others taking the seeds after it
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    #[command(flatten)]
+    jobs: JobArgs,
+}
+
+#[derive(Args)]
+struct DiffArgs {
+    /// Days to check: `all`, a year, a day, or `<year>/<day>` with an optional `/<part>`
+    #[arg(value_parser = parse_day, default_value = "all")]
+    day: Days,
+    /// Size of the inputs, by default the small size the day's tests use
+    #[arg(long, short)]
+    size: Option<usize>,
+    /// Number of inputs per day
+    #[arg(long, default_value_t = 100)]
+    seeds: u64,
+    /// Seed of the first input, the others taking the seeds after it
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    #[command(flatten)]
+    jobs: JobArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    day: u8,
+    /// Year of the day, for a year other than the one at the root, kept in a directory of its own
+    #[arg(long)]
+    year: Option<u16>,
+    /// Root of the repository
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    root: PathBuf,
+}
+
+/// Which solvers to run: `all`, a year like `2022`, a day of `aoc_core::YEAR` like `5`, or
+/// `2022/05` and `2022/05/2` for a day or a single part of any year.
+#[derive(Clone, Copy)]
+struct Days {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+}
+
+impl Days {
+    fn contains(self, solver: &Solver) -> bool {
+        self.year.is_none_or(|year| year == solver.year)
+            && self.day.is_none_or(|day| day == solver.day)
+            && self.part.is_none_or(|part| part == solver.part)
+    }
+
+    fn single(self) -> bool {
+        self.day.is_some()
+    }
+}
+
+fn parse_day(s: &str) -> Result<Days, String> {
+    let invalid = || format!("expected `all`, a year, a day or `<year>/<day>[/<part>]`, got {:?}", s);
+    let day = |d: &str| d.parse().ok().filter(|d| (1..=25).contains(d)).ok_or_else(invalid);
+    let year = |y: &str| y.parse().ok().filter(|y| (2015..=9999).contains(y)).ok_or_else(invalid);
+    let fields: Vec<&str> = s.split('/').collect();
+    match fields[..] {
+        ["all"] => Ok(Days { year: None, day: None, part: None }),
+        [n] if n.len() == 4 => Ok(Days { year: Some(year(n)?), day: None, part: None }),
+        [n] => Ok(Days { year: Some(YEAR), day: Some(day(n)?), part: None }),
+        [y, d, ref part @ ..] if part.len() <= 1 => Ok(Days {
+            year: Some(year(y)?),
+            day: Some(day(d)?),
+            part: part.first().map(|p| p.parse().map_err(|_| invalid())).transpose()?,
+        }),
+        _ => Err(invalid()),
+    }
+}
+
+fn select(days: Days, part: Option<u8>) -> Result<Vec<&'static Solver>, String> {
+    let part = match (days.part, part) {
+        (Some(a), Some(b)) if a != b => return Err(format!("part {} and --part {} disagree", a, b)),
+        (a, b) => a.or(b),
+    };
+    let selected: Vec<&Solver> = solvers()
+        .into_iter()
+        .filter(|s| days.contains(s) && part.is_none_or(|p| s.part == p))
+        .collect();
+    match selected.is_empty() {
+        true => Err("no solvers match".to_string()),
+        false => Ok(selected),
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    if !args.day.single() && args.input.is_some() {
+        return Err("--input needs a single day".to_string());
+    }
+    if !args.day.single() && args.render.is_some() {
+        return Err("--render needs a single day".to_string());
+    }
+    let selected = select(args.day, args.part)?;
+    if args.render.is_some() && selected.iter().all(|s| aoc_grid::render::find(s.year, s.day, s.part).is_none()) {
+        return Err("none of the parts draws anything".to_string());
+    }
+    if args.allocs && !alloc::COUNTING {
+        return Err("--allocs needs the runner built with the `count-allocs` feature".to_string());
+    }
+    if let Some(path) = &args.trace {
+        if !aoc_core::trace::ENABLED {
+            return Err("--trace needs the runner built with `--features trace`".to_string());
+        }
+        let file = fs::File::create(path).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+        aoc_core::trace::install(Box::new(io::BufWriter::new(file)));
+    }
+    let mut failed = false;
+    let mut jobs = Vec::new();
+    for day in selected.chunk_by(|a, b| a.puzzle() == b.puzzle()) {
+        let input = match args.day.single() {
+            false => read_default(day[0].dir),
+            true => resolve(args.input.as_deref(), day[0].dir),
+        };
+        match input {
+            Ok(input) => {
+                let input: Arc<str> = input.into();
+                jobs.extend(day.iter().map(|&solver| Job::new(solver, input.clone())));
+            }
+            Err(e) => {
+                eprintln!("{}: {}", day[0].puzzle(), e);
+                failed = true;
+            }
+        }
+    }
+    execute(&jobs, args.jobs.threads(), args.jobs.timeout(), |job, outcome| {
+        let solver = job.solver;
+        match outcome {
+            Outcome::Done(Ok(answer), times, allocs) => {
+                let mut details = Vec::new();
+                if args.time {
+                    details.push(times.to_string());
+                }
+                if args.allocs {
+                    details.push(allocs.to_string());
+                }
+                match details.is_empty() {
+                    true => println!("{} part {}: {}", solver.puzzle(), solver.part, answer),
+                    false => println!("{} part {}: {} ({})", solver.puzzle(), solver.part, answer, details.join("; ")),
+                }
+            }
+            Outcome::Done(Err(e), ..) => {
+                eprintln!("{} part {}: ERROR: {}", solver.puzzle(), solver.part, report(&*e, &job.input));
+                failed = true;
+            }
+            outcome => {
+                eprintln!("{} part {}: {}", solver.puzzle(), solver.part, outcome);
+                failed = true;
+            }
+        }
+    });
+    if let Err(e) = aoc_core::trace::finish() {
+        eprintln!("could not write the trace: {}", e);
+        failed = true;
+    }
+    if let (Some(path), Some(job)) = (&args.render, jobs.first()) {
+        if let Err(e) = render::render(&selected, &job.input, path, args.frames) {
+            eprintln!("{}", e);
+            failed = true;
+        }
+    }
+    match failed {
+        true => Err("some solvers failed".to_string()),
+        false => Ok(()),
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let baseline = args.baseline.as_deref().map(bench::read).transpose()?;
+    let source = match args.generated {
+        true => bench::Source::Generated { size: args.size, seed: args.seed },
+        false => bench::Source::Real,
+    };
+    let timeout = (args.timeout > 0.0).then(|| Duration::from_secs_f64(args.timeout));
+    let (report, complete) = bench::bench(&select(args.day, None)?, args.runs, &source, timeout)?;
+    let format = args.format.or(args.output.as_deref().map(bench::Format::of)).unwrap_or(bench::Format::Json);
+    let text = bench::write(&report, format);
+    match &args.output {
+        Some(path) => fs::write(path, text).map_err(|e| format!("could not write {}: {}", path.display(), e))?,
+        None => print!("{}", text),
+    }
+    let min = Duration::from_secs_f64(args.min_ms / 1000.0);
+    if !baseline.is_none_or(|baseline| bench::compare(&report, &baseline, args.threshold, min)) {
+        return Err("some parts regressed".to_string());
+    }
+    match complete {
+        true => Ok(()),
+        false => Err("some solvers failed".to_string()),
+    }
+}
+
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    let (Some(year), Some(day)) = (args.day.year, args.day.day) else {
+        return Err("generate needs a single day".to_string());
+    };
+    let puzzle = Puzzle { year, day };
+    let generator = aoc_core::generate::find(year, day).ok_or(format!("{} has no generator", puzzle))?;
+    let input = generator.input(args.size, args.seed) + "\n";
+    match &args.output {
+        Some(path) => fs::write(path, input).map_err(|e| format!("could not write {}: {}", path.display(), e)),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn new(args: NewArgs) -> Result<(), String> {
+    let dir = scaffold::new(&args.root, args.day, args.year.filter(|&year| year != YEAR))?;
+    println!("Created {}; fill in its examples and input, then rebuild the runner", dir.display());
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Generate(args) => generate(args),
+        Command::New(args) => new(args),
+        Command::Stress(args) => {
+            let (threads, timeout) = (args.jobs.threads(), args.jobs.timeout());
+            select(args.day, None).and_then(|solvers| {
+                match stress::stress(&solvers, args.size, args.seed, args.seeds, threads, timeout)? {
+                    true => Ok(()),
+                    false => Err("some runs failed".to_string()),
+                }
+            })
+        }
+        Command::Diff(args) => {
+            let (threads, timeout) = (args.jobs.threads(), args.jobs.timeout());
+            select(args.day, None).and_then(|solvers| {
+                match diff::diff(&solvers, args.size, args.seed, args.seeds, threads, timeout)? {
+                    true => Ok(()),
+                    false => Err("some solvers disagreed with their reference".to_string()),
+                }
+            })
+        }
+        Command::Check(args) => {
+            let answers = Answers::load(&args.answers);
+            let (threads, timeout) = (args.jobs.threads(), args.jobs.timeout());
+            answers.and_then(|answers| match check::check(&select(args.day, None)?, &answers, threads, timeout) {
+                true => Ok(()),
+                false => Err("some answers are wrong".to_string()),
+            })
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days() {
+        let days = |s| parse_day(s).map(|d| (d.year, d.day, d.part));
+        assert_eq!(days("5"), Ok((Some(YEAR), Some(5), None)));
+        assert_eq!(days("2022"), Ok((Some(2022), None, None)));
+        assert_eq!(days("2022/05/2"), Ok((Some(2022), Some(5), Some(2))));
+        for s in ["0", "26", "99", "2014", "10000", "99/5"] {
+            assert!(days(s).is_err(), "{} should be invalid", s);
+        }
+    }
+}
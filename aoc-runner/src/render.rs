@@ -0,0 +1,53 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use aoc_core::{parse::report, registry::Solver};
+use aoc_grid::render::{encode, find, Format};
+
+/// `path` with the part and the frame number added to its name, e.g. `loop.ppm` becomes
+/// `loop-2-0007.ppm` for the seventh frame of part 2.
+fn numbered(path: &Path, part: Option<u8>, frame: Option<usize>) -> PathBuf {
+    let mut name = path.file_stem().unwrap_or_default().to_os_string();
+    if let Some(part) = part {
+        name.push(format!("-{}", part));
+    }
+    if let Some(frame) = frame {
+        name.push(format!("-{:04}", frame));
+    }
+    if let Some(extension) = path.extension() {
+        name.push(".");
+        name.push(extension);
+    }
+    path.with_file_name(name)
+}
+
+/// Draws what the solvers among `solvers` that have a renderer work out on `input`, and writes it
+/// to `path` in the format its extension asks for, or to the terminal for `-`. Only the last frame
+/// is written unless `all_frames`, which writes every frame to a numbered file. When several parts
+/// draw, each gets its own file.
+pub fn render(solvers: &[&'static Solver], input: &str, path: &Path, all_frames: bool) -> Result<(), String> {
+    let drawing: Vec<_> = solvers.iter().filter_map(|&s| Some((s, find(s.year, s.day, s.part)?.render))).collect();
+    let format = Format::of(path);
+    for &(solver, render) in &drawing {
+        let mut frames = render(input)
+            .map_err(|e| format!("{} part {}: ERROR: {}", solver.puzzle(), solver.part, report(&*e, input)))?;
+        if !all_frames {
+            frames = frames.split_off(frames.len().saturating_sub(1));
+        }
+        let part = (drawing.len() > 1).then_some(solver.part);
+        for (i, frame) in frames.iter().enumerate() {
+            let image = encode(frame, format);
+            if path == Path::new("-") {
+                let mut stdout = io::stdout().lock();
+                stdout.write_all(&image).and_then(|_| writeln!(stdout)).map_err(|e| e.to_string())?;
+                continue;
+            }
+            let path = numbered(path, part, all_frames.then_some(i + 1));
+            fs::write(&path, image).map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A day crate in the repository.
+struct DayCrate {
+    /// Package name, e.g. `day-05`, or `aoc2022-day-05` for a day of another year.
+    package: String,
+    /// Directory relative to the repository root, e.g. `2022/day-05`.
+    dir: String,
+}
+
+impl DayCrate {
+    fn new(day: u8, year: Option<u16>) -> Self {
+        match year {
+            Some(year) => Self { package: format!("aoc{}-day-{:02}", year, day), dir: format!("{}/day-{:02}", year, day) },
+            None => Self { package: format!("day-{:02}", day), dir: format!("day-{:02}", day) },
+        }
+    }
+
+    fn lib(&self) -> String {
+        self.package.replace('-', "_")
+    }
+
+    /// The way from the crate's directory back up to the repository root.
+    fn up(&self) -> String {
+        "../".repeat(self.dir.split('/').count())
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+fn manifest(krate: &DayCrate) -> String {
+    format!(
+        r#"[package]
+name = "{package}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-core = {{ version = "0.1.0", path = "{up}aoc-core" }}
+aoc-macros = {{ version = "0.1.0", path = "{up}aoc-macros" }}
+"#,
+        package = krate.package,
+        up = krate.up(),
+    )
+}
+
+/// Solvers that count the lines, so the new crate builds and its tests pass on the empty examples
+/// until the real ones are filled in.
+const LIB: &str = r#"use aoc_macros::aoc;
+
+pub fn parse(inp: &str) -> Vec<&str> {
+    inp.lines().collect()
+}
+
+#[aoc(test = "0")]
+pub fn part_1(lines: &[&str]) -> usize {
+    lines.len()
+}
+
+#[aoc(test = "0")]
+pub fn part_2(lines: &[&str]) -> usize {
+    lines.len()
+}
+"#;
+
+/// Adds `pattern` to the members of the workspace in `Cargo.toml` at `root`, unless it is there.
+fn add_member(root: &Path, pattern: &str) -> Result<(), String> {
+    let path = root.join("Cargo.toml");
+    let text = read(&path)?;
+    let manifest: toml::Table = text.parse().map_err(|e| format!("invalid {}: {}", path.display(), e))?;
+    let members = manifest
+        .get("workspace")
+        .and_then(|w| w.get("members"))
+        .and_then(|m| m.as_array())
+        .ok_or(format!("{} has no workspace members", path.display()))?;
+    if members.iter().any(|m| m.as_str() == Some(pattern)) {
+        return Ok(());
+    }
+    let invalid = || format!("could not find the members of {}", path.display());
+    let workspace = text.find("[workspace]").ok_or_else(invalid)?;
+    let key = workspace + text[workspace..].find("\nmembers").ok_or_else(invalid)?;
+    let open = key + text[key..].find('[').ok_or_else(invalid)? + 1;
+    let close = open + text[open..].find(']').ok_or_else(invalid)?;
+    let list = text[open..close].trim_end();
+    let end = open + list.len();
+    let comma = match list.trim_start().is_empty() || list.ends_with(',') {
+        true => "",
+        false => ",",
+    };
+    // A list over several lines gets the pattern on a line of its own, indented like the last one.
+    let member = match text[open..close].contains('\n') {
+        true => {
+            let last = list.rsplit('\n').next().unwrap_or_default();
+            let indent = match last.trim().is_empty() {
+                true => "    ",
+                false => &last[..last.len() - last.trim_start().len()],
+            };
+            format!("{}\n{}\"{}\",", comma, indent, pattern)
+        }
+        false if list.trim_start().is_empty() => format!("\"{}\"", pattern),
+        false => format!("{} \"{}\"", comma, pattern),
+    };
+    write(&path, &format!("{}{}{}", &text[..end], member, &text[end..]))
+}
+
+/// Every day crate in the repository: `day-XX` at the root and `<year>/day-XX` for other years.
+fn day_crates(root: &Path) -> Result<Vec<DayCrate>, String> {
+    let list = |dir: &Path| -> Result<Vec<String>, String> {
+        let entries = fs::read_dir(dir).map_err(|e| format!("could not read {}: {}", dir.display(), e))?;
+        Ok(entries.flatten().filter_map(|e| e.file_name().into_string().ok()).collect())
+    };
+    let mut crates = Vec::new();
+    for name in list(root)? {
+        if let Some(day) = name.strip_prefix("day-").and_then(|d| d.parse().ok()) {
+            crates.push(DayCrate::new(day, None));
+        } else if let Ok(year) = name.parse() {
+            for day in list(&root.join(&name))? {
+                if let Some(day) = day.strip_prefix("day-").and_then(|d| d.parse().ok()) {
+                    crates.push(DayCrate::new(day, Some(year)));
+                }
+            }
+        }
+    }
+    crates.retain(|krate| root.join(&krate.dir).join("Cargo.toml").is_file());
+    // This repository's own days first, then the other years.
+    crates.sort_by(|a, b| (a.dir.contains('/'), &a.dir).cmp(&(b.dir.contains('/'), &b.dir)));
+    Ok(crates)
+}
+
+/// Makes the runner depend on every day crate and rewrites `days.rs`, which links them.
+fn regenerate_runner(root: &Path) -> Result<(), String> {
+    let crates = day_crates(root)?;
+    let path = root.join("aoc-runner/Cargo.toml");
+    let mut text = read(&path)?;
+    let manifest: toml::Table = text.parse().map_err(|e| format!("invalid {}: {}", path.display(), e))?;
+    let dependencies = manifest.get("dependencies").and_then(|d| d.as_table()).cloned().unwrap_or_default();
+    // New dependencies go after the last day, before any section following the dependencies.
+    let section = text.find("[dependencies]").ok_or(format!("{} has no dependencies", path.display()))?;
+    let mut at = text[section..].find("\n[").map_or(text.len(), |end| section + end + 1);
+    while text[..at].ends_with("\n\n") {
+        at -= 1;
+    }
+    for krate in crates.iter().filter(|krate| !dependencies.contains_key(&krate.package)) {
+        let line = format!("{} = {{ version = \"0.1.0\", path = \"../{}\" }}\n", krate.package, krate.dir);
+        text.insert_str(at, &line);
+        at += line.len();
+    }
+    write(&path, &text)?;
+
+    let mut days = String::from("//! Links every day so its solvers end up in `aoc_core::SOLVERS`. Written by `aoc new`.\n\n");
+    for krate in &crates {
+        days += &format!("use {} as _;\n", krate.lib());
+    }
+    write(&root.join("aoc-runner/src/days.rs"), &days)
+}
+
+/// Creates the crate for `day` of `year`, or of this repository's own year without one, in the
+/// repository at `root`, and registers it with the workspace and the runner. Returns its directory.
+pub fn new(root: &Path, day: u8, year: Option<u16>) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} is not between 1 and 25", day));
+    }
+    let krate = DayCrate::new(day, year);
+    let dir = root.join(&krate.dir);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    fs::create_dir_all(dir.join("src")).map_err(|e| format!("could not create {}: {}", dir.display(), e))?;
+    write(&dir.join("Cargo.toml"), &manifest(&krate))?;
+    write(&dir.join("src/lib.rs"), LIB)?;
+    write(&dir.join("src/main.rs"), &format!("aoc_macros::main!({});\n", krate.lib()))?;
+    for file in ["test-1.txt", "test-2.txt", "input.txt"] {
+        write(&dir.join(file), "")?;
+    }
+    if let Some(year) = year {
+        add_member(root, &format!("{}/day-*", year))?;
+    }
+    regenerate_runner(root)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUNNER: &str = r#"[package]
+name = "aoc-runner"
+
+[dependencies]
+aoc-core = { version = "0.1.0", path = "../aoc-core" }
+day-01 = { version = "0.1.0", path = "../day-01" }
+
+[features]
+default = []
+"#;
+
+    /// A repository with day 1 of this year only, whose workspace lists `members` as given.
+    fn repository(name: &str, members: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc-runner/src")).unwrap();
+        fs::create_dir_all(root.join("day-01")).unwrap();
+        fs::write(root.join("Cargo.toml"), format!("[workspace]\nresolver = \"2\"\nmembers = {}\n", members)).unwrap();
+        fs::write(root.join("aoc-runner/Cargo.toml"), RUNNER).unwrap();
+        fs::write(root.join("day-01/Cargo.toml"), "").unwrap();
+        root
+    }
+
+    fn members(root: &Path) -> Vec<String> {
+        let manifest: toml::Table = read(&root.join("Cargo.toml")).unwrap().parse().unwrap();
+        let members = manifest["workspace"]["members"].as_array().unwrap();
+        members.iter().map(|m| m.as_str().unwrap().to_string()).collect()
+    }
+
+    fn dependencies(root: &Path) -> Vec<String> {
+        let manifest: toml::Table = read(&root.join("aoc-runner/Cargo.toml")).unwrap().parse().unwrap();
+        manifest["dependencies"].as_table().unwrap().keys().cloned().collect()
+    }
+
+    #[test]
+    fn new_year() {
+        let root = repository("new-year", r#"["aoc-core", "aoc-runner", "day-*"]"#);
+        new(&root, 5, Some(2022)).unwrap();
+        assert_eq!(members(&root), ["aoc-core", "aoc-runner", "day-*", "2022/day-*"]);
+        assert_eq!(dependencies(&root), ["aoc-core", "aoc2022-day-05", "day-01"]);
+        let days = read(&root.join("aoc-runner/src/days.rs")).unwrap();
+        assert!(days.ends_with("use day_01 as _;\nuse aoc2022_day_05 as _;\n"), "{}", days);
+        assert!(read(&root.join("aoc-runner/Cargo.toml")).unwrap().ends_with("\n\n[features]\ndefault = []\n"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn second_day_of_a_year() {
+        let root = repository("second-day", r#"["aoc-runner", "day-*"]"#);
+        new(&root, 5, Some(2022)).unwrap();
+        new(&root, 6, Some(2022)).unwrap();
+        assert_eq!(members(&root), ["aoc-runner", "day-*", "2022/day-*"]);
+        assert_eq!(dependencies(&root), ["aoc-core", "aoc2022-day-05", "aoc2022-day-06", "day-01"]);
+        assert!(new(&root, 6, Some(2022)).is_err());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn members_on_several_lines() {
+        let root = repository("several-lines", "[\n    \"aoc-runner\",\n    \"day-*\",\n]");
+        new(&root, 5, Some(2022)).unwrap();
+        assert_eq!(members(&root), ["aoc-runner", "day-*", "2022/day-*"]);
+        let text = read(&root.join("Cargo.toml")).unwrap();
+        assert!(text.ends_with("    \"day-*\",\n    \"2022/day-*\",\n]\n"), "{}", text);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,51 @@
+use std::{sync::Arc, time::Duration};
+
+use aoc_core::{generate, parse::report, registry::Solver};
+
+use crate::exec::{execute, Job, Outcome};
+
+/// Runs `solvers` on inputs generated with `seeds` consecutive seeds from `first`, and prints every
+/// run that failed, panicked or timed out. Days without a generator are skipped. Returns whether
+/// every run succeeded.
+pub fn stress(
+    solvers: &[&'static Solver],
+    size: Option<usize>,
+    first: u64,
+    seeds: u64,
+    threads: usize,
+    timeout: Option<Duration>,
+) -> Result<bool, String> {
+    let mut jobs = Vec::new();
+    let mut runs = Vec::new();
+    for day in solvers.chunk_by(|a, b| a.puzzle() == b.puzzle()) {
+        let Some(generator) = generate::find(day[0].year, day[0].day) else {
+            eprintln!("{}: no generator, skipped", day[0].puzzle());
+            continue;
+        };
+        for seed in first..first + seeds {
+            let input: Arc<str> = generator.input(size, seed).into();
+            for &solver in day {
+                jobs.push(Job::new(solver, input.clone()));
+                runs.push((seed, size.unwrap_or(generator.size)));
+            }
+        }
+    }
+    if jobs.is_empty() {
+        return Err("no generators match".to_string());
+    }
+    let mut failed = 0;
+    let mut runs = runs.into_iter();
+    execute(&jobs, threads, timeout, |job, outcome| {
+        let solver = job.solver;
+        let (seed, size) = runs.next().unwrap();
+        let error = match outcome {
+            Outcome::Done(Ok(_), ..) => return,
+            Outcome::Done(Err(e), ..) => format!("ERROR: {}", report(&*e, &job.input)),
+            outcome => outcome.to_string(),
+        };
+        failed += 1;
+        eprintln!("{} part {}, size {}, seed {}: {}", solver.puzzle(), solver.part, size, seed, error);
+    });
+    println!("{} runs, {} failed", jobs.len(), failed);
+    Ok(failed == 0)
+}
@@ -0,0 +1,24 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+use crate::NUMBERS;
+
+/// `size` lines of letters, digits and spelled out digits, each with at least one digit.
+#[generator(size = 1000, test_size = 50)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::with_capacity(size);
+    for _ in 0..size {
+        let mut line = String::new();
+        for _ in 0..rng.usize(1..8) {
+            match rng.u8(0..4) {
+                0 => line.push(rng.char('0'..='9')),
+                1 => line += NUMBERS[rng.usize(1..10)],
+                _ => line.extend((0..rng.usize(1..5)).map(|_| rng.lowercase())),
+            }
+        }
+        let digit = rng.usize(0..=line.len());
+        line.insert(digit, rng.char('1'..='9'));
+        lines.push(line);
+    }
+    lines.join("\n")
+}
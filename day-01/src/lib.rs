@@ -0,0 +1,56 @@
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+const NUMBERS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The calibration document, one line per value.
+pub fn parse(inp: &str) -> Vec<String> {
+    inp.lines().map(str::to_string).collect()
+}
+
+#[aoc(test="142", part=1, answer = "55607", reference = reference::part_1)]
+pub fn part_1(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|x| {
+            x.chars().find_map(|y| y.to_digit(10)).unwrap() * 10
+                + x.chars().rev().find_map(|c| c.to_digit(10)).unwrap()
+        })
+        .sum()
+}
+
+#[aoc(cases(("test-2.txt", "281"), ("overlaps.txt", "238")), answer = "55291", reference = reference::part_2)]
+pub fn part_2(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|x| {
+            let mut first = None;
+            let mut last = None;
+            let mut iter = x.chars();
+            for i in 0..x.len() {
+                let c = iter.next().unwrap();
+                if c.is_ascii_digit() {
+                    last = c.to_digit(10);
+                    if first.is_none() {
+                        first = c.to_digit(10);
+                    }
+                    continue;
+                }
+                for (n_i, &num) in NUMBERS.iter().enumerate() {
+                    if i + num.len() <= x.len() && num == &x[i..i + num.len()] {
+                        last = Some(n_i as u32);
+                        if first.is_none() {
+                            first = Some(n_i as u32);
+                        }
+                        break;
+                    }
+                }
+            }
+            first.unwrap() * 10 + last.unwrap()
+        })
+        .sum()
+}
@@ -0,0 +1,34 @@
+//! Naive solvers that look for a digit, or a spelled out one, at every position of a line.
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// The digits of `line` in order, spelled out ones included when `spelled` is set.
+fn digits(line: &str, spelled: bool) -> Vec<u32> {
+    let mut digits = Vec::new();
+    for i in 0..line.len() {
+        let rest = &line[i..];
+        if let Some(d) = rest.chars().next().and_then(|c| c.to_digit(10)) {
+            digits.push(d);
+        } else if spelled {
+            digits.extend((1..).zip(WORDS).filter(|(_, w)| rest.starts_with(w)).map(|(d, _)| d));
+        }
+    }
+    digits
+}
+
+fn calibration(inp: &str, spelled: bool) -> u32 {
+    inp.lines()
+        .map(|line| {
+            let digits = digits(line, spelled);
+            digits[0] * 10 + digits[digits.len() - 1]
+        })
+        .sum()
+}
+
+pub fn part_1(inp: &str) -> u32 {
+    calibration(inp, false)
+}
+
+pub fn part_2(inp: &str) -> u32 {
+    calibration(inp, true)
+}
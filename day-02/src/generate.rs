@@ -0,0 +1,23 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// `size` games of one to six rounds, each showing up to 20 cubes of some of the colors.
+#[generator(size = 100, test_size = 20)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut games = Vec::with_capacity(size);
+    for id in 1..=size {
+        let rounds: Vec<String> = (0..rng.usize(1..=6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                colors[..rng.usize(1..=3)]
+                    .iter()
+                    .map(|color| format!("{} {}", rng.u32(1..=20), color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        games.push(format!("Game {}: {}", id, rounds.join("; ")));
+    }
+    games.join("\n")
+}
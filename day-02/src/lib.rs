@@ -0,0 +1,94 @@
+use std::str::FromStr;
+
+use aoc_core::parse::{field, lines, ParseError};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+#[derive(Default, Debug)]
+pub struct Round {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut round = Round::default();
+        for cubes in value.split(", ") {
+            let (n, color) = cubes
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(value, cubes, "`<count> <color>`"))?;
+            let n = field(value, n, "a cube count")?;
+            match color {
+                "red" => round.red = n,
+                "green" => round.green = n,
+                "blue" => round.blue = n,
+                _ => return Err(ParseError::at(value, color, "red, green or blue")),
+            }
+        }
+        Ok(round)
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (id, rest) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(line, line, "`Game <id>: ...`"))?;
+        let id = id
+            .strip_prefix("Game ")
+            .ok_or_else(|| ParseError::at(line, id, "`Game <id>`"))?;
+        let id = field(line, id, "a game id")?;
+        let rounds = rest
+            .split("; ")
+            .map(|round| round.parse().map_err(|e: ParseError| e.inside(line, round)))
+            .collect::<Result<_, _>>()?;
+        Ok(Game { id, rounds })
+    }
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Game>, ParseError> {
+    lines(inp)
+}
+
+#[aoc(test = "8", malformed = "Game 1: 3 purple", malformed = "Game one: 3 red", answer = "2683", reference = reference::part_1)]
+pub fn part_1(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter_map(|g| {
+            if g.rounds
+                .iter()
+                .all(|r| r.red <= 12 && r.green <= 13 && r.blue <= 14)
+            {
+                Some(g.id)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+#[aoc(test = "2286", malformed = "Game 1: red 3", answer = "49710", reference = reference::part_2)]
+pub fn part_2(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|g| {
+            g.rounds.iter().fold((0, 0, 0), |(r, g, b), round| {
+                (r.max(round.red), g.max(round.green), b.max(round.blue))
+            })
+        })
+        .map(|(r, g, b)| r * g * b)
+        .sum()
+}
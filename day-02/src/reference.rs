@@ -0,0 +1,45 @@
+//! Naive solvers that collect every count of cubes shown and compare them one by one.
+
+/// The id of every game, with every count of cubes shown and their colour.
+fn games(inp: &str) -> Vec<(u32, Vec<(u32, &str)>)> {
+    inp.lines()
+        .map(|line| {
+            let (game, rounds) = line.split_once(": ").unwrap();
+            let id = game["Game ".len()..].parse().unwrap();
+            let shown = rounds
+                .split("; ")
+                .flat_map(|round| round.split(", "))
+                .map(|shown| {
+                    let (count, colour) = shown.split_once(' ').unwrap();
+                    (count.parse().unwrap(), colour)
+                })
+                .collect();
+            (id, shown)
+        })
+        .collect()
+}
+
+pub fn part_1(inp: &str) -> u32 {
+    let limit = |colour| match colour {
+        "red" => 12,
+        "green" => 13,
+        _ => 14,
+    };
+    games(inp)
+        .into_iter()
+        .filter(|(_, shown)| shown.iter().all(|&(count, colour)| count <= limit(colour)))
+        .map(|(id, _)| id)
+        .sum()
+}
+
+pub fn part_2(inp: &str) -> u32 {
+    games(inp)
+        .into_iter()
+        .map(|(_, shown)| {
+            ["red", "green", "blue"]
+                .map(|colour| shown.iter().filter(|s| s.1 == colour).map(|s| s.0).max().unwrap_or(0))
+                .iter()
+                .product::<u32>()
+        })
+        .sum()
+}
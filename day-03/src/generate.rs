@@ -0,0 +1,34 @@
+use aoc_core::generate::Rng;
+use aoc_grid::{Grid, Point};
+use aoc_macros::generator;
+
+const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+/// A `size` by `size` schematic of numbers up to three digits and symbols, half of them gears.
+#[generator(size = 140, test_size = 30)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut grid = Grid::new(size, size, '.');
+    for row in 0..size {
+        let mut col = rng.usize(0..4);
+        while col < size {
+            if rng.u8(0..3) == 0 {
+                let symbol = match rng.bool() {
+                    true => '*',
+                    false => SYMBOLS[rng.usize(..SYMBOLS.len())] as char,
+                };
+                grid[Point::new(row, col)] = symbol;
+                col += 1;
+            } else {
+                let digits = rng.u32(1..=3).min((size - col) as u32);
+                let number = rng.u32(10u32.pow(digits - 1)..10u32.pow(digits));
+                for (i, digit) in number.to_string().chars().enumerate() {
+                    grid[Point::new(row, col + i)] = digit;
+                }
+                col += digits as usize;
+            }
+            // Numbers next to each other on a line would read as one.
+            col += rng.usize(1..8);
+        }
+    }
+    grid.to_string()
+}
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use aoc_core::parse::ParseError;
+use aoc_grid::{Grid, Point, RaggedRow};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+/// The numbers in the schematic, with the points their digits cover.
+fn numbers(grid: &Grid<char>) -> Vec<(u32, Vec<Point>)> {
+    let mut numbers = Vec::new();
+    for (row, line) in grid.rows().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            let digits = line[col..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 {
+                col += 1;
+                continue;
+            }
+            let number = line[col..col + digits].iter().fold(0, |acc, c| acc * 10 + c.to_digit(10).unwrap());
+            numbers.push((number, (col..col + digits).map(|col| Point::new(row, col)).collect()));
+            col += digits;
+        }
+    }
+    numbers
+}
+
+pub fn parse(inp: &str) -> Result<Grid<char>, ParseError> {
+    inp.parse().map_err(|e: RaggedRow| ParseError::ragged(inp, e.row, e.width))
+}
+
+#[aoc(test = "4361", malformed = "467..\n...*", answer = "537832", reference = reference::part_1)]
+pub fn part_1(grid: &Grid<char>) -> u32 {
+    numbers(grid)
+        .into_iter()
+        .filter(|(_, points)| {
+            points
+                .iter()
+                .flat_map(|&p| grid.neighbours_8(p))
+                .any(|p| !grid[p].is_ascii_digit() && grid[p] != '.')
+        })
+        .map(|(number, _)| number)
+        .sum()
+}
+
+#[aoc(test = "467835", answer = "81939900", reference = reference::part_2)]
+pub fn part_2(grid: &Grid<char>) -> u32 {
+    // Make a mask of the input, with each coordinate holding the index of the number it is a part
+    // of, so equal numbers around a gear still count twice.
+    let numbers = numbers(grid);
+    let mut number_mask = Grid::new(grid.width(), grid.height(), None);
+    for (i, (_, points)) in numbers.iter().enumerate() {
+        for &p in points {
+            number_mask[p] = Some(i);
+        }
+    }
+    let mut sum = 0;
+    for (p, &c) in grid.iter() {
+        if c == '*' {
+            let parts: HashSet<usize> = grid.neighbours_8(p).filter_map(|p| number_mask[p]).collect();
+            if parts.len() == 2 {
+                sum += parts.iter().map(|&i| numbers[i].0).product::<u32>();
+            }
+        }
+    }
+    sum
+}
@@ -0,0 +1,56 @@
+//! Naive solvers that check every number against every symbol for whether they touch.
+
+/// Every number in the schematic with its row and the columns it spans.
+fn numbers(rows: &[&[u8]]) -> Vec<(u32, usize, usize, usize)> {
+    let mut numbers = Vec::new();
+    for (row, line) in rows.iter().enumerate() {
+        let mut start = 0;
+        while start < line.len() {
+            let mut end = start;
+            while end < line.len() && line[end].is_ascii_digit() {
+                end += 1;
+            }
+            if end > start {
+                let number = std::str::from_utf8(&line[start..end]).unwrap().parse().unwrap();
+                numbers.push((number, row, start, end));
+                start = end;
+            } else {
+                start += 1;
+            }
+        }
+    }
+    numbers
+}
+
+/// Whether `(row, col)` touches the number in `row_n` from `start` up to `end`, diagonals included.
+fn touches((row, col): (usize, usize), (_, row_n, start, end): (u32, usize, usize, usize)) -> bool {
+    row.abs_diff(row_n) <= 1 && col + 1 >= start && col <= end
+}
+
+pub fn part_1(inp: &str) -> u32 {
+    let rows: Vec<&[u8]> = inp.lines().map(str::as_bytes).collect();
+    let symbols: Vec<(usize, usize)> = (0..rows.len())
+        .flat_map(|row| (0..rows[row].len()).map(move |col| (row, col)))
+        .filter(|&(row, col)| rows[row][col] != b'.' && !rows[row][col].is_ascii_digit())
+        .collect();
+    numbers(&rows)
+        .into_iter()
+        .filter(|&number| symbols.iter().any(|&s| touches(s, number)))
+        .map(|number| number.0)
+        .sum()
+}
+
+pub fn part_2(inp: &str) -> u32 {
+    let rows: Vec<&[u8]> = inp.lines().map(str::as_bytes).collect();
+    let numbers = numbers(&rows);
+    let mut sum = 0;
+    for (row, line) in rows.iter().enumerate() {
+        for col in (0..line.len()).filter(|&col| line[col] == b'*') {
+            let parts: Vec<u32> = numbers.iter().filter(|&&n| touches((row, col), n)).map(|n| n.0).collect();
+            if parts.len() == 2 {
+                sum += parts[0] * parts[1];
+            }
+        }
+    }
+    sum
+}
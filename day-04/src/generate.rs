@@ -0,0 +1,23 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// `size` cards of ten numbers and 25 winning numbers. Most cards win nothing and the others up to
+/// three numbers, never winning copies past the last card, so the number of copies stays small.
+#[generator(size = 200, test_size = 30)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut cards = Vec::with_capacity(size);
+    for id in 1..=size {
+        let mut numbers: Vec<u32> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+        let matches = match rng.u8(0..3) {
+            0 => rng.usize(1..=3).min(size - id),
+            _ => 0,
+        };
+        let (card, rest) = numbers.split_at(10);
+        let mut winning = [&card[..matches], &rest[..25 - matches]].concat();
+        rng.shuffle(&mut winning);
+        let join = |numbers: &[u32]| numbers.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" ");
+        cards.push(format!("Card {:>3}: {} | {}", id, join(card), join(&winning)));
+    }
+    cards.join("\n")
+}
@@ -0,0 +1,71 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_core::parse::{field, lines, ParseError};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+pub struct Card {
+    numbers: HashSet<u32>,
+    winning: HashSet<u32>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (_, numbers) = value
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(value, value, "`Card <id>: ...`"))?;
+        let (card, winning) = numbers
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::at(value, numbers, "`<numbers> | <winning numbers>`"))?;
+        let numbers = card
+            .split_whitespace()
+            .map(|x| field(value, x, "a number"))
+            .collect::<Result<_, _>>()?;
+        let winning = winning
+            .split_whitespace()
+            .map(|x| field(value, x, "a number"))
+            .collect::<Result<_, _>>()?;
+        Ok(Card { numbers, winning })
+    }
+}
+
+impl Card {
+    fn winning(&self) -> u32 {
+        self.numbers.intersection(&self.winning).count() as u32
+    }
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Card>, ParseError> {
+    lines(inp)
+}
+
+/// A card is worth one point for its first winning number, and doubles that for each one after.
+/// `scores.txt` holds cards with none and with three or more, which scoring 2^(n/2) got wrong.
+#[aoc(cases(("test.txt", "13"), ("scores.txt", "20")), malformed = "Card 1: 41 48 83 | 83 x6", answer = "26218", reference = reference::part_1)]
+pub fn part_1(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|c| match c.winning() {
+            0 => 0,
+            n => 2_u32.pow(n - 1),
+        })
+        .sum()
+}
+
+#[aoc(test = "30", malformed = "Card 1: 41 48 83", answer = "9997537", reference = reference::part_2)]
+pub fn part_2(cards: &[Card]) -> u32 {
+    let mut count = vec![1; cards.len()];
+    for (i, c) in cards.iter().enumerate() {
+        let copies = *count.get(i).unwrap();
+        for j in 1..=c.winning() {
+            if let Some(c) = count.get_mut(i + j as usize) {
+                *c += copies;
+            }
+        }
+    }
+    count.iter().sum()
+}
@@ -0,0 +1,28 @@
+//! Naive solvers that count the matches of each card and hand out the copies one at a time.
+
+/// How many of each card's numbers are winning numbers.
+fn matches(inp: &str) -> Vec<usize> {
+    inp.lines()
+        .map(|line| {
+            let (numbers, winning) = line.split_once(": ").unwrap().1.split_once(" | ").unwrap();
+            let winning: Vec<&str> = winning.split_whitespace().collect();
+            numbers.split_whitespace().filter(|n| winning.contains(n)).count()
+        })
+        .collect()
+}
+
+pub fn part_1(inp: &str) -> u32 {
+    matches(inp).into_iter().map(|m| if m == 0 { 0 } else { 1 << (m - 1) }).sum()
+}
+
+/// Scratches every card and every copy won one at a time.
+pub fn part_2(inp: &str) -> u32 {
+    let matches = matches(inp);
+    let mut pile: Vec<usize> = (0..matches.len()).collect();
+    let mut scratched = 0;
+    while let Some(card) = pile.pop() {
+        scratched += 1;
+        pile.extend((card + 1..=card + matches[card]).filter(|&won| won < matches.len()));
+    }
+    scratched
+}
@@ -0,0 +1,43 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+/// Numbers are below 2^32 in a real almanac.
+const BITS: usize = 32;
+
+/// Ten seed ranges and seven maps of `size` ranges each. Each map splits the numbers below 2^32
+/// into ranges and lays them out again in another order, so every seed maps to another number
+/// below 2^32. Small almanacs stick to numbers below 2^(size + 2), few enough seeds to try them
+/// one by one.
+#[generator(size = 30, test_size = 8)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let limit: u64 = 1 << (size + 2).min(BITS);
+    let mut seeds = Vec::new();
+    for _ in 0..10 {
+        let start = rng.u64(..limit - 1);
+        seeds.push(start.to_string());
+        seeds.push(rng.u64(1..=(limit - start).min(limit / 10).max(1)).to_string());
+    }
+    let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+    for names in CATEGORIES.windows(2) {
+        let mut cuts: Vec<u64> = (1..size).map(|_| rng.u64(1..limit)).collect();
+        cuts.extend([0, limit]);
+        cuts.sort();
+        cuts.dedup();
+        let mut ranges: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        rng.shuffle(&mut ranges);
+        let mut dest = 0;
+        let mut lines = vec![format!("{}-to-{} map:", names[0], names[1])];
+        for (src, len) in ranges {
+            // Like in a real almanac, some numbers are left out and map to themselves.
+            if lines.len() == 1 || rng.u8(0..5) > 0 {
+                lines.push(format!("{} {} {}", dest, src, len));
+            }
+            dest += len;
+        }
+        sections.push(lines.join("\n"));
+    }
+    sections.join("\n\n")
+}
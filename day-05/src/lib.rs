@@ -0,0 +1,143 @@
+use std::str::FromStr;
+
+use aoc_core::parse::{field, lines, ParseError};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+type SeedRange = (u64, u64);
+
+pub struct Range {
+    src_start: u64,
+    dest_start: u64,
+    len: u64
+}
+
+impl Range {
+    fn map(&self, src: u64) -> Option<u64> {
+        if self.src_start <= src && src < self.src_start + self.len {
+            Some(self.dest_start + (src - self.src_start))
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut values = value.split(' ');
+        let mut next = |expected| match values.next() {
+            Some(x) => field(value, x, expected),
+            None => Err(ParseError::end(value, expected)),
+        };
+        let range = Range {
+            dest_start: next("a destination start")?,
+            src_start: next("a source start")?,
+            len: next("a range length")?
+        };
+        match values.next() {
+            Some(x) => Err(ParseError::at(value, x, "the end of the range")),
+            None => Ok(range),
+        }
+    }
+}
+
+pub struct Map {
+    ranges: Vec<Range>
+}
+
+impl Map {
+    fn map(&self, src: u64) -> u64 {
+        self.ranges.iter().find_map(|r| r.map(src)).unwrap_or(src)
+    }
+    fn map_range(&self, src: Vec<SeedRange>) -> Vec<SeedRange> {
+        let mut new_mapping = Vec::new();
+        for (src, len) in src.iter() {
+            let mut cur_src = *src;
+            let mut cur_len = *len;
+            for range in &self.ranges {
+                // The part before a range maps to itself.
+                if cur_src < range.src_start && range.src_start < cur_src + cur_len {
+                    new_mapping.push((cur_src, range.src_start - cur_src));
+                    cur_len -= range.src_start - cur_src;
+                    cur_src = range.src_start;
+                }
+                if range.src_start <= cur_src && cur_src < range.src_start + range.len {
+                    if cur_src + cur_len <= range.src_start + range.len {
+                        new_mapping.push((range.dest_start + (cur_src - range.src_start), cur_len));
+                        cur_len = 0;
+                        break
+                    } else {
+                        new_mapping.push((range.dest_start + (cur_src - range.src_start), range.src_start + range.len - cur_src));
+                        let new_src = range.src_start + range.len;
+                        cur_len -= new_src - cur_src;
+                        cur_src = new_src;
+                    }
+                }
+            }
+            if cur_len > 0 {
+                new_mapping.push((cur_src, cur_len));
+            }
+        }
+        new_mapping
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (header, ranges) = value.split_once('\n').unwrap_or((value, ""));
+        if !header.ends_with(" map:") {
+            return Err(ParseError::at(value, header, "`<source>-to-<destination> map:`"));
+        }
+        let mut ranges: Vec<Range> = lines(ranges).map_err(|e| e.inside(value, ranges))?;
+        ranges.sort_by_key(|range| range.src_start);
+        Ok(Map {
+            ranges
+        })
+    }
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    /// The maps, in order.
+    pub maps: Vec<Map>,
+}
+
+pub fn parse(inp: &str) -> Result<Almanac, ParseError> {
+    let mut sections = inp.split("\n\n");
+    let seeds = sections.next().unwrap_or_default();
+    let numbers = seeds
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| ParseError::at(inp, seeds, "`seeds: <numbers>`"))?;
+    let seeds = numbers
+        .split(' ')
+        .map(|x| field(inp, x, "a seed number"))
+        .collect::<Result<_, _>>()?;
+    let maps = sections
+        .map(|map| map.parse().map_err(|e: ParseError| e.inside(inp, map)))
+        .collect::<Result<_, _>>()?;
+    Ok(Almanac { seeds, maps })
+}
+
+#[aoc(test="35", malformed = "seeds: 79 14\n\nseed-to-soil map:\n50 98", answer = "51752125", reference = reference::part_1)]
+pub fn part_1(almanac: &Almanac) -> u64 {
+    let mut seeds = almanac.seeds.clone();
+    for map in &almanac.maps {
+        seeds = seeds.iter().map(|s| map.map(*s)).collect();
+    }
+    *seeds.iter().min().unwrap()
+}
+
+#[aoc(test="46", malformed = "seeds: 79 x14\n\nseed-to-soil map:\n50 98 2", answer = "12634632", reference = reference::part_2)]
+pub fn part_2(almanac: &Almanac) -> u64 {
+    let mut seeds: Vec<SeedRange> = almanac.seeds.chunks_exact(2).map(|s| (s[0], s[1])).collect();
+    for map in &almanac.maps {
+        seeds = map.map_range(seeds);
+    }
+    seeds.iter().map(|&s| s.0).min().unwrap()
+}
@@ -0,0 +1,47 @@
+//! Naive solvers that run every seed through the maps, every seed in the ranges for part 2.
+
+/// The `(destination, source, length)` ranges of a map.
+type Map = Vec<(u64, u64, u64)>;
+
+/// The seed numbers, and every map in order.
+fn almanac(inp: &str) -> (Vec<u64>, Vec<Map>) {
+    let mut sections = inp.split("\n\n");
+    let seeds = sections.next().unwrap()["seeds: ".len()..].split(' ').map(|s| s.parse().unwrap()).collect();
+    let maps = sections
+        .map(|map| {
+            map.lines()
+                .skip(1)
+                .map(|line| {
+                    let numbers: Vec<u64> = line.split(' ').map(|n| n.parse().unwrap()).collect();
+                    (numbers[0], numbers[1], numbers[2])
+                })
+                .collect()
+        })
+        .collect();
+    (seeds, maps)
+}
+
+fn location(maps: &[Map], seed: u64) -> u64 {
+    maps.iter().fold(seed, |n, map| {
+        match map.iter().find(|&&(_, source, len)| (source..source + len).contains(&n)) {
+            Some(&(dest, source, _)) => dest + n - source,
+            None => n,
+        }
+    })
+}
+
+pub fn part_1(inp: &str) -> u64 {
+    let (seeds, maps) = almanac(inp);
+    seeds.into_iter().map(|seed| location(&maps, seed)).min().unwrap()
+}
+
+/// Tries every seed in every range.
+pub fn part_2(inp: &str) -> u64 {
+    let (seeds, maps) = almanac(inp);
+    seeds
+        .chunks(2)
+        .flat_map(|range| range[0]..range[0] + range[1])
+        .map(|seed| location(&maps, seed))
+        .min()
+        .unwrap()
+}
@@ -0,0 +1,29 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// Whether a boat can beat `record` in a race of `time`.
+fn beatable(time: u128, record: u128) -> bool {
+    (time / 2) * (time - time / 2) > record
+}
+
+/// `size` races, at most four as part 2 joins them into one long race. Every record is set by
+/// holding the button for some time, and the joined record can be beaten too.
+#[generator(size = 4, test_size = 3)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 4);
+    loop {
+        let races: Vec<(u64, u64)> = (0..size)
+            .map(|_| {
+                let time = rng.u64(10..100);
+                let hold = rng.u64(1..time / 2);
+                (time, hold * (time - hold))
+            })
+            .collect();
+        let join = |f: fn(&(u64, u64)) -> u64| races.iter().map(|r| f(r).to_string()).collect::<String>();
+        let (time, record) = (join(|r| r.0), join(|r| r.1));
+        if beatable(time.parse().unwrap(), record.parse().unwrap()) {
+            let line = |f: fn(&(u64, u64)) -> u64| races.iter().map(|r| format!(" {:>4}", f(r))).collect::<String>();
+            return format!("Time:    {}\nDistance:{}", line(|r| r.0), line(|r| r.1));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use aoc_core::parse::{field, ParseError};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+pub struct Race {
+    pub dist: u64,
+    pub time: u64,
+}
+
+/// The numbers after `label` on `line`, a line of the sheet `inp`.
+fn numbers(inp: &str, line: Option<&str>, label: &str) -> Result<Vec<u64>, ParseError> {
+    let expected = format!("`{} <numbers>`", label);
+    let line = line.ok_or_else(|| ParseError::end(inp, expected.as_str()))?;
+    line.strip_prefix(label)
+        .ok_or_else(|| ParseError::at(inp, line, expected.as_str()))?
+        .split_whitespace()
+        .map(|x| field(inp, x, "a number"))
+        .collect()
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = inp.lines();
+    let times = numbers(inp, lines.next(), "Time:")?;
+    let distances = numbers(inp, lines.next(), "Distance:")?;
+    Ok(times
+        .iter()
+        .zip(distances.iter())
+        .map(|(&time, &dist)| Race { dist, time })
+        .collect())
+}
+
+/// The number of ways to beat the record of `race`.
+fn ways(race: &Race) -> u64 {
+    let mut start = 0;
+    for i in 0..race.time {
+        if (race.time - i) * i > race.dist {
+            start = i;
+            break;
+        }
+    }
+    let mut end = 0;
+    for i in (0..=race.time).rev() {
+        if (race.time - i) * i > race.dist {
+            end = i;
+            break;
+        }
+    }
+    end - start + 1
+}
+
+#[aoc(test = "288", malformed = "Time: 7 1x\nDistance: 9 40", malformed = "Time: 7 15\nRecord: 9 40", answer = "633080", reference = reference::part_1)]
+pub fn part_1(races: &[Race]) -> u64 {
+    races.iter().map(ways).product()
+}
+
+#[aoc(test = "71503", answer = "20048741", reference = reference::part_2)]
+pub fn part_2(races: &[Race]) -> u64 {
+    // Without the spaces between the numbers, the races are one long race.
+    let join = |f: fn(&Race) -> u64| races.iter().map(|r| f(r).to_string()).collect::<String>().parse().unwrap();
+    ways(&Race {
+        time: join(|r| r.time),
+        dist: join(|r| r.dist),
+    })
+}
@@ -0,0 +1,24 @@
+//! Naive solvers that race every way of holding the button instead of solving the quadratic.
+
+/// Races every way of holding the button and counts the ones beating `record`.
+fn ways(time: u64, record: u64) -> u64 {
+    (0..=time).filter(|hold| hold * (time - hold) > record).count() as u64
+}
+
+/// The numbers after the label of each line, with their spaces.
+fn lines(inp: &str) -> Vec<&str> {
+    inp.lines().map(|line| line.split_once(':').unwrap().1).collect()
+}
+
+pub fn part_1(inp: &str) -> u64 {
+    let lines = lines(inp);
+    let numbers = |line: &str| line.split_whitespace().map(|n| n.parse().unwrap()).collect::<Vec<u64>>();
+    let (times, records) = (numbers(lines[0]), numbers(lines[1]));
+    times.iter().zip(&records).map(|(&time, &record)| ways(time, record)).product()
+}
+
+pub fn part_2(inp: &str) -> u64 {
+    let lines = lines(inp);
+    let number = |line: &str| line.replace(' ', "").parse().unwrap();
+    ways(number(lines[0]), number(lines[1]))
+}
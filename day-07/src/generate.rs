@@ -0,0 +1,22 @@
+use std::collections::HashSet;
+
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+const CARDS: &[u8] = b"AKQJT98765432";
+
+/// `size` different hands with bids up to 1000. Hands are drawn from a few kinds of card at a time,
+/// so every hand type shows up.
+#[generator(size = 1000, test_size = 50)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut hands = HashSet::new();
+    let mut bids = Vec::with_capacity(size);
+    while bids.len() < size {
+        let kinds: Vec<u8> = (0..rng.usize(1..=5)).map(|_| CARDS[rng.usize(..CARDS.len())]).collect();
+        let hand: String = (0..5).map(|_| kinds[rng.usize(..kinds.len())] as char).collect();
+        if hands.insert(hand.clone()) {
+            bids.push(format!("{} {}", hand, rng.u32(1..=1000)));
+        }
+    }
+    bids.join("\n")
+}
@@ -0,0 +1,187 @@
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+use aoc_core::parse::{field, lines, ParseError};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+#[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Debug)]
+pub enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl TryFrom<char> for Card {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'A' => Self::Ace,
+            'K' => Self::King,
+            'Q' => Self::Queen,
+            'J' => Self::Jack,
+            'T' => Self::Ten,
+            '9' => Self::Nine,
+            '8' => Self::Eight,
+            '7' => Self::Seven,
+            '6' => Self::Six,
+            '5' => Self::Five,
+            '4' => Self::Four,
+            '3' => Self::Three,
+            '2' => Self::Two,
+            _ => return Err(()),
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum HandType {
+    HighCard,
+    Pair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+    pub jokers: bool,
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let cards = value
+            .char_indices()
+            .map(|(i, c)| {
+                Card::try_from(c).map_err(|_| ParseError::at(value, &value[i..i + c.len_utf8()], "a card"))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            cards,
+            jokers: false,
+        })
+    }
+}
+
+impl Hand {
+    /// The same hand, with its jacks played as jokers.
+    pub fn with_jokers(self) -> Self {
+        let cards = self
+            .cards
+            .into_iter()
+            .map(|c| if c == Card::Jack { Card::Joker } else { c })
+            .collect();
+        Self { cards, jokers: true }
+    }
+
+    pub fn get_hand_type(&self) -> HandType {
+        let mut count = HashMap::new();
+        for card in &self.cards {
+            *count.entry(card).or_insert(0) += 1;
+        }
+        let mut jokers = 0;
+        if self.jokers {
+            jokers = *count.get(&Card::Joker).unwrap_or(&0);
+        }
+        let max = *count
+            .iter()
+            .filter(|item| **item.0 != Card::Joker)
+            .map(|item| item.1)
+            .max()
+            .unwrap_or(&0);
+        if max + jokers == 5 {
+            HandType::FiveOfAKind
+        } else if max + jokers == 4 {
+            HandType::FourOfAKind
+        } else if max == 3 && count.values().any(|v| *v == 2)
+            || max + jokers == 3 && count.values().filter(|v| **v == 2).count() == 2
+        {
+            HandType::FullHouse
+        } else if max + jokers == 3 {
+            HandType::ThreeOfAKind
+        } else if max == 2 && (jokers == 1 || count.values().filter(|&v| *v == 2).count() == 2) {
+            HandType::TwoPair
+        } else if max + jokers == 2 || jokers == 1 {
+            HandType::Pair
+        } else {
+            HandType::HighCard
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_hand_type()
+            .cmp(&other.get_hand_type())
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+pub struct Bid {
+    pub hand: Hand,
+    pub bid: u32,
+}
+
+impl FromStr for Bid {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (hand, bid) = value
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(value, value, "`<hand> <bid>`"))?;
+        let hand = hand.parse().map_err(|e: ParseError| e.inside(value, hand))?;
+        let bid = field(value, bid, "a bid")?;
+        Ok(Self { hand, bid })
+    }
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Bid>, ParseError> {
+    lines(inp)
+}
+
+fn winnings(mut bids: Vec<Bid>) -> u32 {
+    bids.sort();
+    bids.iter()
+        .enumerate()
+        .map(|(i, bid)| bid.bid * (i + 1) as u32)
+        .sum()
+}
+
+#[aoc(test = "6440", malformed = "32T3K 765\nT55X5 684", answer = "250120186", reference = reference::part_1)]
+pub fn part_1(bids: &[Bid]) -> u32 {
+    winnings(bids.to_vec())
+}
+
+#[aoc(test = "5905", malformed = "32T3K 76a5", answer = "250665248", reference = reference::part_2)]
+pub fn part_2(bids: &[Bid]) -> u32 {
+    winnings(
+        bids.iter()
+            .map(|bid| Bid { hand: bid.hand.clone().with_jokers(), bid: bid.bid })
+            .collect(),
+    )
+}
@@ -0,0 +1,54 @@
+//! Naive solvers that count the cards of each hand and try every card as the jokers.
+
+/// The type of a hand without jokers, from high card at 0 to five of a kind at 6.
+fn kind(hand: &[u8]) -> u8 {
+    let mut counts: Vec<usize> = hand.iter().map(|c| hand.iter().filter(|&d| d == c).count()).collect();
+    counts.sort();
+    counts.reverse();
+    match counts[..] {
+        [5, ..] => 6,
+        [4, ..] => 5,
+        [3, 3, 3, 2, 2] => 4,
+        [3, ..] => 3,
+        [2, 2, 2, 2, 1] => 2,
+        [2, ..] => 1,
+        _ => 0,
+    }
+}
+
+/// The best type of a hand with every joker played as each other card in turn.
+fn best_kind(hand: &[u8]) -> u8 {
+    match hand.iter().position(|&c| c == b'J') {
+        Some(joker) => b"AKQT98765432"
+            .iter()
+            .map(|&card| {
+                let mut hand = hand.to_vec();
+                hand[joker] = card;
+                best_kind(&hand)
+            })
+            .max()
+            .unwrap(),
+        None => kind(hand),
+    }
+}
+
+fn winnings(inp: &str, order: &[u8], kind: fn(&[u8]) -> u8) -> u32 {
+    let mut hands: Vec<(u8, Vec<usize>, u32)> = inp
+        .lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ').unwrap();
+            let strengths = hand.bytes().map(|c| order.iter().position(|&o| o == c).unwrap()).collect();
+            (kind(hand.as_bytes()), strengths, bid.parse().unwrap())
+        })
+        .collect();
+    hands.sort();
+    (1..).zip(hands).map(|(rank, hand)| rank * hand.2).sum()
+}
+
+pub fn part_1(inp: &str) -> u32 {
+    winnings(inp, b"23456789TJQKA", kind)
+}
+
+pub fn part_2(inp: &str) -> u32 {
+    winnings(inp, b"J23456789TQKA", best_kind)
+}
@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+const PRIMES: [usize; 6] = [2, 3, 5, 7, 11, 13];
+
+/// A node name of three letters and digits, ending in `end`.
+fn name(rng: &mut Rng, taken: &mut HashSet<String>, end: char) -> String {
+    loop {
+        let mut name: String = (0..2).map(|_| rng.alphanumeric().to_ascii_uppercase()).collect();
+        name.push(end);
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// `size` instructions and one to six ghosts. A ghost walks a loop of the instructions' length
+/// times a prime, from its start to its goal and from its goal back to the node after its start,
+/// so it reaches a goal after every multiple of that length. The first ghost goes from `AAA` to
+/// `ZZZ`. The branch not taken out of a node leads anywhere.
+#[generator(size = 270, test_size = 10)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let path: String = (0..size.max(1)).map(|_| if rng.bool() { 'L' } else { 'R' }).collect();
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+    let mut taken = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    // Every ghost's nodes, in the order it walks them.
+    let mut ghosts = Vec::new();
+    for (i, &prime) in primes[..rng.usize(1..=6)].iter().enumerate() {
+        let steps = path.len() * prime;
+        let mut nodes = match i {
+            0 => vec!["AAA".to_string()],
+            _ => vec![name(rng, &mut taken, 'A')],
+        };
+        for _ in 1..steps {
+            let end = loop {
+                let c = rng.alphanumeric().to_ascii_uppercase();
+                if c != 'A' && c != 'Z' {
+                    break c;
+                }
+            };
+            nodes.push(name(rng, &mut taken, end));
+        }
+        nodes.push(match i {
+            0 => "ZZZ".to_string(),
+            _ => name(rng, &mut taken, 'Z'),
+        });
+        ghosts.push(nodes);
+    }
+    let all: Vec<&String> = ghosts.iter().flatten().collect();
+    let mut lines = Vec::new();
+    for nodes in &ghosts {
+        for (t, node) in nodes.iter().enumerate() {
+            // The goal continues like the start.
+            let next = match t + 1 {
+                n if n == nodes.len() => &nodes[1],
+                n => &nodes[n],
+            };
+            let other = all[rng.usize(..all.len())];
+            let (left, right) = match path.as_bytes()[t % path.len()] {
+                b'L' => (next, other),
+                _ => (other, next),
+            };
+            lines.push(format!("{} = ({}, {})", node, left, right));
+        }
+    }
+    rng.shuffle(&mut lines);
+    format!("{}\n\n{}", path, lines.join("\n"))
+}
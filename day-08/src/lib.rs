@@ -0,0 +1,80 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::parse::ParseError;
+use aoc_graph::{lcm, steps_until};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+pub struct Map {
+    /// The left and right instructions, repeated forever.
+    pub path: String,
+    /// The left and right node of every node.
+    pub nodes: HashMap<String, (String, String)>,
+}
+
+pub fn parse(inp: &str) -> Result<Map, ParseError> {
+    let (path, nodes) = inp
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end(inp, "a blank line followed by the nodes"))?;
+    if let Some(i) = path.find(|c| c != 'L' && c != 'R') {
+        return Err(ParseError::at(inp, &path[i..i + 1], "L or R"));
+    }
+    let mut edges = Vec::new();
+    for node in nodes.lines() {
+        let expected = "`<node> = (<left>, <right>)`";
+        let (parent, children) = node.split_once(" = ").ok_or_else(|| ParseError::at(inp, node, expected))?;
+        let (left, right) = children
+            .strip_prefix('(')
+            .and_then(|c| c.strip_suffix(')'))
+            .and_then(|c| c.split_once(", "))
+            .ok_or_else(|| ParseError::at(inp, children, "`(<left>, <right>)`"))?;
+        edges.push((parent, (left, right)));
+    }
+    let parents: HashSet<&str> = edges.iter().map(|&(parent, _)| parent).collect();
+    if let Some(child) = edges.iter().flat_map(|&(_, (left, right))| [left, right]).find(|child| !parents.contains(child)) {
+        return Err(ParseError::at(inp, child, "a node with a line of its own"));
+    }
+    let nodes = edges
+        .into_iter()
+        .map(|(parent, (left, right))| (parent.to_string(), (left.to_string(), right.to_string())))
+        .collect();
+    Ok(Map { path: path.to_string(), nodes })
+}
+
+/// The number of steps from `start` until a node matching `is_goal`.
+fn steps(map: &Map, start: &str, is_goal: impl Fn(&str) -> bool) -> u64 {
+    let path = map.path.as_bytes();
+    // Every node at every point of the path: after that many steps the walk repeats itself.
+    let limit = (map.nodes.len() * path.len()) as u64;
+    steps_until(
+        (start, 0),
+        |&(node, i)| {
+            let entry = &map.nodes[node];
+            let node = match path[i % path.len()] {
+                b'L' => entry.0.as_str(),
+                b'R' => entry.1.as_str(),
+                _ => unreachable!("Wrong character in path!"),
+            };
+            (node, i + 1)
+        },
+        |(node, _)| is_goal(node),
+        limit,
+    )
+    .unwrap_or_else(|| panic!("no goal can be reached from {}", start))
+}
+
+#[aoc(test = "6", part = 1, malformed = "LXR\n\nAAA = (AAA, AAA)", malformed = "LR\n\nAAA = (AAA, ZZZ)", answer = "16343", reference = reference::part_1)]
+pub fn part_1(map: &Map) -> u64 {
+    steps(map, "AAA", |node| node == "ZZZ")
+}
+
+#[aoc(test = "6", part = 2, answer = "15299095336639", reference = reference::part_2)]
+pub fn part_2(map: &Map) -> u64 {
+    lcm(map
+        .nodes
+        .keys()
+        .filter(|t| t.ends_with('A'))
+        .map(|start| steps(map, start, |node| node.ends_with('Z'))))
+}
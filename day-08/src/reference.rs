@@ -0,0 +1,30 @@
+//! Naive solvers that walk from all starts at once instead of combining the cycles of each.
+
+use std::collections::HashMap;
+
+/// Walks from every node `start` picks at once, until all of them are on a node ending in `goal`.
+fn walk(inp: &str, start: impl Fn(&str) -> bool, goal: &str) -> u64 {
+    let (path, nodes) = inp.split_once("\n\n").unwrap();
+    let nodes: HashMap<&str, (&str, &str)> =
+        nodes.lines().map(|line| (&line[0..3], (&line[7..10], &line[12..15]))).collect();
+    let mut ghosts: Vec<&str> = nodes.keys().copied().filter(|node| start(node)).collect();
+    let mut steps = 0;
+    for turn in path.bytes().cycle() {
+        if ghosts.iter().all(|ghost| ghost.ends_with(goal)) {
+            break;
+        }
+        for ghost in &mut ghosts {
+            *ghost = if turn == b'L' { nodes[ghost].0 } else { nodes[ghost].1 };
+        }
+        steps += 1;
+    }
+    steps
+}
+
+pub fn part_1(inp: &str) -> u64 {
+    walk(inp, |node| node == "AAA", "ZZZ")
+}
+
+pub fn part_2(inp: &str) -> u64 {
+    walk(inp, |node| node.ends_with('A'), "Z")
+}
@@ -0,0 +1,23 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+fn binomial(n: i64, k: i64) -> i64 {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+/// `size` sequences of 21 values of polynomials up to degree six with small coefficients, so the
+/// differences reach zero and the extrapolated values stay far from overflowing.
+#[generator(size = 200, test_size = 30)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut lines = Vec::with_capacity(size);
+    for _ in 0..size {
+        // In the basis of binomial coefficients every integer coefficient gives integer values.
+        let coefficients: Vec<i64> = (0..=rng.usize(0..=6)).map(|_| rng.i64(-5..=5)).collect();
+        let start = rng.i64(0..5);
+        let values: Vec<String> = (start..start + 21)
+            .map(|x| coefficients.iter().zip(0..).map(|(c, k)| c * binomial(x, k)).sum::<i64>().to_string())
+            .collect();
+        lines.push(values.join(" "));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,59 @@
+use aoc_core::parse::{field, ParseError};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+fn get_sequences(s: Vec<i32>) -> Vec<Vec<i32>> {
+    let mut s = s;
+    let mut diff = vec![s.clone()];
+    loop {
+        let mut new_sequence = Vec::new();
+        for i in 0..s.len() - 1 {
+            new_sequence.push(s[i + 1] - s[i])
+        }
+        if new_sequence.iter().all(|x| *x == 0) {
+            break;
+        };
+        s = new_sequence.clone();
+        diff.push(new_sequence);
+    }
+    diff
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    inp.lines()
+        .map(|line| match line.trim().is_empty() {
+            true => Err(ParseError::at(inp, line, "a sequence of numbers")),
+            false => line.split_whitespace().map(|v| field(inp, v, "a number")).collect(),
+        })
+        .collect()
+}
+
+#[aoc(test = "114", malformed = "0 3 6 9\n1 3 x 10", malformed = "0 3 6 9\n\n1 3 6 10", answer = "1930746032", reference = reference::part_1)]
+pub fn part_1(sequences: &[Vec<i32>]) -> i32 {
+    let mut sum = 0;
+    for s in sequences {
+        let diff = get_sequences(s.clone());
+        let mut x = 0;
+        for s in diff.iter().rev() {
+            x += s.iter().last().unwrap();
+        }
+        sum += x;
+    }
+    sum
+}
+
+#[aoc(test = "2", answer = "1154", reference = reference::part_2)]
+pub fn part_2(sequences: &[Vec<i32>]) -> i32 {
+    let mut sum = 0;
+    for s in sequences {
+        let diff = get_sequences(s.clone());
+        let mut x = 0;
+        for s in diff.iter().rev() {
+            x = s.iter().next().unwrap() - x;
+        }
+        sum += x;
+    }
+    sum
+}
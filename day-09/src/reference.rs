@@ -0,0 +1,29 @@
+//! Naive solvers that extrapolate by Lagrange interpolation instead of differences.
+
+/// The value at `x` of the polynomial through the values of `history` at 0, 1, 2..., by Lagrange
+/// interpolation instead of differences.
+fn extrapolate(history: &[i128], x: i128) -> i128 {
+    let n = history.len() as i128;
+    (0..n)
+        .map(|i| {
+            let (mut num, mut den) = (history[i as usize], 1);
+            for j in (0..n).filter(|&j| j != i) {
+                num *= x - j;
+                den *= i - j;
+            }
+            num / den
+        })
+        .sum()
+}
+
+fn histories(inp: &str) -> impl Iterator<Item = Vec<i128>> + '_ {
+    inp.lines().map(|line| line.split_whitespace().map(|v| v.parse().unwrap()).collect())
+}
+
+pub fn part_1(inp: &str) -> i128 {
+    histories(inp).map(|h| extrapolate(&h, h.len() as i128)).sum()
+}
+
+pub fn part_2(inp: &str) -> i128 {
+    histories(inp).map(|h| extrapolate(&h, -1)).sum()
+}
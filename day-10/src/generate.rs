@@ -0,0 +1,93 @@
+use aoc_core::generate::Rng;
+use aoc_grid::{
+    Direction::{self, *},
+    Grid, Point,
+};
+use aoc_macros::generator;
+
+/// The pipe connecting `dirs`, as in the input.
+fn pipe(dirs: &[Direction]) -> char {
+    match dirs {
+        [Up, Down] => '|',
+        [Up, Right] => 'L',
+        [Up, Left] => 'J',
+        [Right, Down] => 'F',
+        [Down, Left] => '7',
+        [Right, Left] => '-',
+        _ => unreachable!("Not a pipe"),
+    }
+}
+
+/// A `size` by `size` field of pipes with one closed loop through the start. The loop goes around
+/// a random shape of 2x2 blocks, one run of blocks per row, each run overlapping the one above so
+/// the loop never touches itself. The other tiles are random pieces of pipe, none leading into the
+/// start.
+#[generator(size = 140, test_size = 20)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let blocks = (size - 1) / 2;
+
+    // The run of blocks in every row of the shape, overlapping the run above.
+    let top = rng.usize(..blocks);
+    let mut runs = Vec::new();
+    let (mut low, mut high) = (0, blocks - 1);
+    for row in top..rng.usize(top..blocks) + 1 {
+        let left = rng.usize(..=high);
+        let right = rng.usize(left.max(low)..blocks);
+        runs.push((row, left, right));
+        (low, high) = (left, right);
+    }
+    let inside = |row: isize, col: isize| {
+        runs.iter().any(|&(r, left, right)| r as isize == row && (left as isize..=right as isize).contains(&col))
+    };
+
+    // Every side of a block on the edge of the shape is three tiles of the loop.
+    let mut loop_dirs: Grid<Vec<Direction>> = Grid::new(size, size, Vec::new());
+    for &(row, left, right) in &runs {
+        for col in left..=right {
+            let corner = Point::new(row * 2, col * 2);
+            for dir in Direction::ALL {
+                let (dr, dc) = dir.offset();
+                if inside(row as isize + dr, col as isize + dc) {
+                    continue;
+                }
+                // The side runs from `start` two tiles along `along`.
+                let (start, along) = match dir {
+                    Up => (corner, Right),
+                    Down => (Point::new(corner.row + 2, corner.col), Right),
+                    Left => (corner, Down),
+                    Right => (Point::new(corner.row, corner.col + 2), Down),
+                };
+                let middle = loop_dirs.step(start, along).unwrap();
+                let end = loop_dirs.step(middle, along).unwrap();
+                loop_dirs[start].push(along);
+                loop_dirs[middle].extend([along.opposite(), along]);
+                loop_dirs[end].push(along.opposite());
+            }
+        }
+    }
+
+    let mut grid = Grid::new(size, size, '.');
+    let mut on_loop = Vec::new();
+    for (p, dirs) in loop_dirs.iter() {
+        if dirs.is_empty() {
+            grid[p] = match rng.u8(0..3) {
+                0 => '.',
+                _ => b"|-LJ7F"[rng.usize(..6)] as char,
+            };
+            continue;
+        }
+        let mut dirs = dirs.clone();
+        dirs.sort_by_key(|&dir| Direction::ALL.iter().position(|&d| d == dir));
+        grid[p] = pipe(&dirs);
+        on_loop.push(p);
+    }
+    let start = on_loop[rng.usize(..on_loop.len())];
+    grid[start] = 'S';
+    for dir in Direction::ALL {
+        if let Some(n) = grid.step(start, dir).filter(|&n| loop_dirs[n].is_empty()) {
+            grid[n] = '.';
+        }
+    }
+    grid.to_string()
+}
@@ -0,0 +1,232 @@
+use std::fmt::Display;
+
+use aoc_core::parse::ParseError;
+use aoc_graph::{reachable, Graph};
+use aoc_grid::{
+    Direction::{self, *},
+    Grid, Point, RaggedRow,
+};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+pub mod render;
+
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub enum Pipe {
+    NS,
+    NE,
+    NW,
+    SE,
+    SW,
+    EW,
+    S,
+    E,
+}
+use Pipe::*;
+
+impl Pipe {
+    /// The directions this pipe leads to.
+    fn connections(&self) -> &'static [Direction] {
+        match self {
+            NS => &[Up, Down],
+            NE => &[Up, Right],
+            NW => &[Up, Left],
+            SE => &[Right, Down],
+            SW => &[Down, Left],
+            EW => &[Right, Left],
+            S | E => &[],
+        }
+    }
+    fn is_horizontally_connected(&self, other: &Pipe) -> bool {
+        self.connections().contains(&Right) && other.connections().contains(&Left)
+    }
+    fn is_vertically_connected(&self, other: &Pipe) -> bool {
+        self.connections().contains(&Down) && other.connections().contains(&Up)
+    }
+}
+
+impl From<char> for Pipe {
+    fn from(value: char) -> Self {
+        match value {
+            '|' => Self::NS,
+            'L' => Self::NE,
+            'J' => Self::NW,
+            'F' => Self::SE,
+            '7' => Self::SW,
+            '-' => Self::EW,
+            'S' => Self::S,
+            _ => Self::E,
+        }
+    }
+}
+
+impl From<Pipe> for char {
+    fn from(pipe: Pipe) -> Self {
+        match pipe {
+            NS => '|',
+            NE => 'L',
+            NW => 'J',
+            SE => 'F',
+            SW => '7',
+            EW => '-',
+            S => 'S',
+            E => '.',
+        }
+    }
+}
+
+impl Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+pub type Map = Grid<Vec<Point>>;
+
+/// Finds where each pipe leads to, and replaces the start with the pipe connecting its two
+/// neighbours, or reports that `inp`, the input of `pipes`, has no start with two neighbours.
+fn get_map(inp: &str, pipes: &mut Grid<Pipe>) -> Result<(Map, Point), ParseError> {
+    let s = pipes.position(|&p| p == S).ok_or_else(|| ParseError::end(inp, "the start tile S"))?;
+    let mut map = pipes.map(|_| Vec::new());
+    for (p, pipe) in pipes.iter() {
+        map[p] = pipe.connections().iter().filter_map(|&dir| pipes.step(p, dir)).collect();
+    }
+    let connected: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|&dir| pipes.step(s, dir).is_some_and(|n| map[n].contains(&s)))
+        .collect();
+    map[s] = connected.iter().filter_map(|&dir| pipes.step(s, dir)).collect();
+    pipes[s] = [NS, NE, NW, SE, SW, EW]
+        .into_iter()
+        .find(|pipe| connected.len() == 2 && pipe.connections().iter().all(|dir| connected.contains(dir)))
+        .ok_or_else(|| ParseError::cell(inp, s.row, s.col, "a start connected to two pipes"))?;
+    Ok((map, s))
+}
+
+pub struct Maze {
+    /// The pipes, with the start replaced by the pipe under it.
+    pub pipes: Grid<Pipe>,
+    /// Where each pipe leads to.
+    pub map: Map,
+    pub start: Point,
+}
+
+pub fn parse(inp: &str) -> Result<Maze, ParseError> {
+    let mut pipes = inp.parse().map_err(|e: RaggedRow| ParseError::ragged(inp, e.row, e.width))?;
+    let (map, start) = get_map(inp, &mut pipes)?;
+    Ok(Maze { pipes, map, start })
+}
+
+#[aoc(test = "8", part = 1, malformed = ".S-7\n.|.|\n.L-J.", malformed = "..-7\n.|.|\n.L-J", malformed = ".S.7\n...|\n.L-J", answer = "6649", reference = reference::part_1)]
+pub fn part_1(maze: &Maze) -> u32 {
+    let (map, s) = (&maze.map, maze.start);
+    let mut last = s;
+    let mut c = map[s][0];
+    let mut len = 1;
+    loop {
+        let prev_last = last;
+        last = c;
+        let neighbours = &map[c];
+        if neighbours[0] != prev_last {
+            c = neighbours[0];
+        } else {
+            c = neighbours[1];
+        }
+        if c == s {
+            break;
+        }
+        len += 1;
+    }
+    len / 2 + 1
+}
+
+/// Doubles the grid, filling in the space between connected pieces of pipe.
+fn expand_pipes(pipes: &Grid<Pipe>) -> Grid<Pipe> {
+    let mut expanded = Grid::new(pipes.width() * 2 - 1, pipes.height() * 2 - 1, E);
+    for (p, pipe) in pipes.iter() {
+        expanded[Point::new(p.row * 2, p.col * 2)] = *pipe;
+        if pipes.step(p, Right).is_some_and(|n| pipe.is_horizontally_connected(&pipes[n])) {
+            expanded[Point::new(p.row * 2, p.col * 2 + 1)] = EW;
+        }
+        if pipes.step(p, Down).is_some_and(|n| pipe.is_vertically_connected(&pipes[n])) {
+            expanded[Point::new(p.row * 2 + 1, p.col * 2)] = NS;
+        }
+    }
+    expanded
+}
+
+fn filter_loop_pipe(pipes: &Grid<Pipe>, mask: &Grid<bool>) -> Grid<Pipe> {
+    let mut filtered = pipes.clone();
+    for (p, &in_loop) in mask.iter() {
+        if !in_loop {
+            filtered[p] = E;
+        }
+    }
+    filtered
+}
+
+fn get_loop_mask(map: &Map, start: Point) -> Grid<bool> {
+    let mut mask = Grid::new(map.width(), map.height(), false);
+    mask[start] = true;
+    let mut last = start;
+    let mut c = map[start][0];
+    loop {
+        mask[c] = true;
+        let prev_last = last;
+        last = c;
+        let neighbours = &map[c];
+        if neighbours[0] != prev_last {
+            c = neighbours[0];
+        } else {
+            c = neighbours[1];
+        }
+        if c == start {
+            break;
+        }
+    }
+    mask
+}
+
+/// The empty cells of a grid, each connected to the empty cells next to it.
+struct Gaps<'a>(&'a Grid<Pipe>);
+
+impl Graph for Gaps<'_> {
+    type Node = Point;
+
+    fn neighbours(&self, &p: &Point) -> impl IntoIterator<Item = Point> {
+        self.0.neighbours(p).filter(|&n| self.0[n] == E)
+    }
+}
+
+fn flood_fill(mask: &mut Grid<bool>, pipes: &Grid<Pipe>) {
+    let edges = pipes
+        .points()
+        .filter(|p| p.row == 0 || p.col == 0 || p.row == pipes.height() - 1 || p.col == pipes.width() - 1)
+        .filter(|&p| pipes[p] == E);
+    for p in reachable(&Gaps(pipes), edges) {
+        if p.row % 2 == 0 && p.col % 2 == 0 {
+            mask[Point::new(p.row / 2, p.col / 2)] = true;
+        }
+    }
+}
+
+/// Marks the tiles of the loop and the tiles outside it, leaving those it encloses.
+fn loop_and_outside(maze: &Maze) -> Grid<bool> {
+    let mut mask = get_loop_mask(&maze.map, maze.start);
+
+    // Filter out all pieces of pipe not connected to the loop
+    let filtered_pipes = filter_loop_pipe(&maze.pipes, &mask);
+
+    // "Zoom in" on the pipes, filling in the space between the pieces
+    let expanded_pipes = expand_pipes(&filtered_pipes);
+
+    // Flood fill from the edges, filing in all spaces not in the loop
+    flood_fill(&mut mask, &expanded_pipes);
+    mask
+}
+
+#[aoc(test = "10", part = 2, answer = "601", reference = reference::part_2, render = render::part_2)]
+pub fn part_2(maze: &Maze) -> u32 {
+    loop_and_outside(maze).iter().filter(|(_, &in_loop)| !in_loop).count() as u32
+}
@@ -0,0 +1,73 @@
+//! Naive solvers that follow the loop around the map and scan each row for the tiles inside it.
+
+/// The `(row, col)` steps a pipe connects, up, right, down and left in that order.
+fn connections(pipe: u8) -> Vec<(isize, isize)> {
+    let all = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+    let open: &[usize] = match pipe {
+        b'|' => &[0, 2],
+        b'-' => &[1, 3],
+        b'L' => &[0, 1],
+        b'J' => &[0, 3],
+        b'7' => &[2, 3],
+        b'F' => &[1, 2],
+        _ => &[],
+    };
+    open.iter().map(|&i| all[i]).collect()
+}
+
+/// The map with the start replaced by the pipe it hides, and the tiles of the loop in order.
+fn find_loop(inp: &str) -> (Vec<Vec<u8>>, Vec<(isize, isize)>) {
+    let mut map: Vec<Vec<u8>> = inp.lines().map(|line| line.bytes().collect()).collect();
+    let at = |map: &[Vec<u8>], (row, col): (isize, isize)| {
+        map.get(row as usize).and_then(|line| line.get(col as usize)).copied().unwrap_or(b'.')
+    };
+    let row = map.iter().position(|line| line.contains(&b'S')).unwrap();
+    let start = (row as isize, map[row].iter().position(|&c| c == b'S').unwrap() as isize);
+    map[start.0 as usize][start.1 as usize] = b"|-LJ7F"
+        .iter()
+        .copied()
+        .find(|&pipe| {
+            connections(pipe)
+                .iter()
+                .all(|&(dr, dc)| connections(at(&map, (start.0 + dr, start.1 + dc))).contains(&(-dr, -dc)))
+        })
+        .unwrap();
+    let mut tiles = vec![start];
+    let mut from = start;
+    let mut tile = start;
+    loop {
+        let pipe = at(&map, tile);
+        let next = connections(pipe)
+            .into_iter()
+            .map(|(dr, dc)| (tile.0 + dr, tile.1 + dc))
+            .find(|&next| next != from)
+            .unwrap();
+        if next == start {
+            return (map, tiles);
+        }
+        (from, tile) = (tile, next);
+        tiles.push(next);
+    }
+}
+
+pub fn part_1(inp: &str) -> usize {
+    find_loop(inp).1.len() / 2
+}
+
+/// Scans every row from the left, flipping between outside and inside on every loop pipe leading
+/// up.
+pub fn part_2(inp: &str) -> usize {
+    let (map, tiles) = find_loop(inp);
+    let mut inside = 0;
+    for (row, line) in map.iter().enumerate() {
+        let mut crossings = 0;
+        for (col, &pipe) in line.iter().enumerate() {
+            if tiles.contains(&(row as isize, col as isize)) {
+                crossings += connections(pipe).contains(&(-1, 0)) as usize;
+            } else if crossings % 2 == 1 {
+                inside += 1;
+            }
+        }
+    }
+    inside
+}
@@ -0,0 +1,21 @@
+//! Pictures of what the solvers work out, for `aoc run --render`.
+
+use aoc_grid::render::{Frame, Pixel, Rgb};
+
+use crate::{get_loop_mask, loop_and_outside, Maze};
+
+/// The loop in yellow, the tiles it encloses as green `I`s and those outside it as blue `O`s.
+pub fn part_2(maze: &Maze) -> Vec<Frame> {
+    let on_loop = get_loop_mask(&maze.map, maze.start);
+    let marked = loop_and_outside(maze);
+    let mut frame = maze.pipes.map(|&pipe| Pixel::new(pipe.into(), Rgb::YELLOW));
+    for p in maze.pipes.points() {
+        if !on_loop[p] {
+            frame[p] = match marked[p] {
+                true => Pixel::new('O', Rgb::BLUE),
+                false => Pixel::new('I', Rgb::GREEN),
+            };
+        }
+    }
+    vec![frame]
+}
@@ -0,0 +1,19 @@
+use aoc_core::generate::Rng;
+use aoc_grid::{Grid, Point};
+use aoc_macros::generator;
+
+/// A `size` by `size` image with a galaxy in about one of 40 places, and about one in ten rows and
+/// columns empty.
+#[generator(size = 140, test_size = 30)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.u8(0..10) == 0).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.u8(0..10) == 0).collect();
+    let mut image = Grid::new(size, size, '.');
+    for p in image.points().collect::<Vec<Point>>() {
+        if !empty_rows[p.row] && !empty_cols[p.col] && rng.u8(0..40) == 0 {
+            image[p] = '#';
+        }
+    }
+    image.to_string()
+}
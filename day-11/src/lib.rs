@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use aoc_core::parse::ParseError;
+use aoc_macros::aoc;
+use itertools::Itertools;
+
+pub mod generate;
+pub mod reference;
+
+type Scale = u64;
+type Coor = (Scale, Scale);
+
+pub struct Map {
+    galaxies: Vec<Coor>,
+    empty_horizontals: Vec<Scale>,
+    empty_verticals: Vec<Scale>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = value.lines().collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut empty_horizontals = Vec::new();
+        let mut empty = vec![true; width];
+        let mut galaxies = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::ragged(value, i, width));
+            }
+            let mut line_empty = true;
+            for (j, (k, c)) in line.char_indices().enumerate() {
+                match c {
+                    '#' => {
+                        empty[j] = false;
+                        line_empty = false;
+                        galaxies.push((i as Scale, j as Scale));
+                    }
+                    '.' => {}
+                    _ => return Err(ParseError::at(value, &line[k..k + c.len_utf8()], ". or #")),
+                }
+            }
+            if line_empty {
+                empty_horizontals.push(i as Scale);
+            }
+        }
+        let empty_verticals: Vec<Scale> = empty
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x)
+            .map(|(i, _)| i as Scale)
+            .collect();
+        Ok(Self {
+            galaxies,
+            empty_horizontals,
+            empty_verticals,
+        })
+    }
+}
+
+impl Map {
+    pub fn calc_distance_sum(&self, scale: Scale) -> Scale {
+        self.galaxies
+            .iter()
+            .tuple_combinations()
+            .map(|(&a, &b)| {
+                a.0.abs_diff(b.0)
+                    + a.1.abs_diff(b.1)
+                    + self
+                        .empty_horizontals
+                        .iter()
+                        .filter(|&&h| (a.0 < h && h < b.0) || (b.0 < h && h < a.0))
+                        .count() as Scale
+                        * (scale - 1)
+                    + self
+                        .empty_verticals
+                        .iter()
+                        .filter(|&&h| (a.1 < h && h < b.1) || (b.1 < h && h < a.1))
+                        .count() as Scale
+                        * (scale - 1)
+            })
+            .sum()
+    }
+}
+
+pub fn parse(inp: &str) -> Result<Map, ParseError> {
+    inp.parse()
+}
+
+#[aoc(test = "374", malformed = "..#\n.#", malformed = "..#\n.x.", answer = "9312968", reference = reference::part_1)]
+pub fn part_1(galaxy_map: &Map) -> Scale {
+    galaxy_map.calc_distance_sum(2)
+}
+
+#[aoc(cases(("test.txt", "1030", 10), ("test.txt", "8410", 100)), params = 1_000_000, answer = "597714117556", reference = reference::part_2)]
+pub fn part_2(galaxy_map: &Map, scale: Scale) -> Scale {
+    galaxy_map.calc_distance_sum(scale)
+}
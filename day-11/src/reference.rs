@@ -0,0 +1,52 @@
+//! Naive solvers that expand the image itself, or walk between galaxies a step at a time.
+
+fn galaxies(image: &[Vec<u8>]) -> Vec<(usize, usize)> {
+    let mut galaxies = Vec::new();
+    for (row, line) in image.iter().enumerate() {
+        galaxies.extend((0..line.len()).filter(|&col| line[col] == b'#').map(|col| (row, col)));
+    }
+    galaxies
+}
+
+/// Doubles the empty rows and columns of the image before measuring.
+pub fn part_1(inp: &str) -> u64 {
+    let mut image: Vec<Vec<u8>> = Vec::new();
+    for line in inp.lines() {
+        image.push(line.bytes().collect());
+        if !line.contains('#') {
+            image.push(line.bytes().collect());
+        }
+    }
+    for col in (0..image[0].len()).rev() {
+        if image.iter().all(|line| line[col] == b'.') {
+            for line in &mut image {
+                line.insert(col, b'.');
+            }
+        }
+    }
+    let galaxies = galaxies(&image);
+    let mut sum = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            sum += (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as u64;
+        }
+    }
+    sum
+}
+
+/// Walks between every pair of galaxies a row and a column at a time, an empty one counting as
+/// `scale` steps.
+pub fn part_2(inp: &str, scale: u64) -> u64 {
+    let image: Vec<Vec<u8>> = inp.lines().map(|line| line.bytes().collect()).collect();
+    let empty_row = |row: usize| !image[row].contains(&b'#');
+    let empty_col = |col: usize| image.iter().all(|line| line[col] == b'.');
+    let galaxies = galaxies(&image);
+    let mut sum = 0;
+    for (i, a) in galaxies.iter().enumerate() {
+        for b in &galaxies[i + 1..] {
+            sum += (a.0.min(b.0) + 1..=a.0.max(b.0)).map(|row| if empty_row(row) { scale } else { 1 }).sum::<u64>();
+            sum += (a.1.min(b.1) + 1..=a.1.max(b.1)).map(|col| if empty_col(col) { scale } else { 1 }).sum::<u64>();
+        }
+    }
+    sum
+}
@@ -0,0 +1,32 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// `size` rows of five to twenty springs. Each row is a real arrangement of damaged springs with
+/// its groups written down, and about a third of its springs then hidden as unknown.
+#[generator(size = 1000, test_size = 20)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut rows = Vec::with_capacity(size);
+    for _ in 0..size {
+        let springs: Vec<bool> = loop {
+            let springs: Vec<bool> = (0..rng.usize(5..=20)).map(|_| rng.bool()).collect();
+            if springs.contains(&true) {
+                break springs;
+            }
+        };
+        let groups: Vec<String> = springs
+            .split(|&damaged| !damaged)
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        let record: String = springs
+            .iter()
+            .map(|&damaged| match (rng.u8(0..3), damaged) {
+                (0, _) => '?',
+                (_, true) => '#',
+                (_, false) => '.',
+            })
+            .collect();
+        rows.push(format!("{} {}", record, groups.join(",")));
+    }
+    rows.join("\n")
+}
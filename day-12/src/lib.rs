@@ -0,0 +1,154 @@
+use std::{fmt::{Debug}, collections::HashMap, str::FromStr};
+
+use aoc_core::{
+    parse::{field, lines, ParseError},
+    trace,
+};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown
+}
+
+impl TryFrom<char> for Spring {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            '?' => Ok(Self::Unknown),
+            _ => Err(())
+        }
+    }
+}
+
+impl Debug for Spring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Spring::Operational => ".",
+            Spring::Damaged => "#",
+            Spring::Unknown => "?",
+        })
+    }
+}
+
+type Cache = HashMap<(Vec<Spring>, Vec<i32>, bool, bool), u64>;
+
+fn count_permutations(cache: &mut Cache, springs: &mut [Spring], config: &mut [i32], space: bool, first: bool) -> u64 {
+    if config.len() == 1 && config[0] == 0 && !springs.contains(&Spring::Damaged) {
+        return 1;
+    }
+    if springs.is_empty() {
+        return 0;
+    }
+    if config[0] < 0 {
+        return 0;
+    }
+    let cached = cache.get(&(springs.to_vec(), config.to_vec(), space, first));
+    trace!("cache", hit = cached.is_some(), springs = springs.len(), groups = config.len());
+    if let Some(x) = cached {
+        return *x
+    }
+    let permutations = match springs[0] {
+        Spring::Operational => count_permutations(cache, &mut springs[1..], config, true, first),
+        Spring::Damaged => {
+            if space && !first {
+                if config[0] > 0 || config.len() <= 1 {
+                    return 0;
+                }
+                config[1] -= 1;
+                let permutations = count_permutations(cache, &mut springs[1..], &mut config[1..], false, false);
+                config[1] += 1;
+                permutations
+            } else {
+                config[0] -= 1;
+                let permutations = count_permutations(cache, &mut springs[1..], config, false, false);
+                config[0] += 1;
+                permutations
+            }
+        },
+        Spring::Unknown => {
+            springs[0] = Spring::Operational;
+            let permutations_1 = count_permutations(cache, springs, config, space, first);
+            springs[0] = Spring::Damaged;
+            let permutations_2 = count_permutations(cache, springs, config, space, first);
+            springs[0] = Spring::Unknown;
+            permutations_1 + permutations_2
+        },
+    };
+    cache.insert((springs.to_vec(), config.to_vec(), space, first), permutations);
+    permutations
+}
+
+/// A row of springs, with the sizes of its groups of damaged springs.
+pub struct Row {
+    pub springs: Vec<Spring>,
+    pub config: Vec<i32>,
+}
+
+impl Row {
+    /// The row as it really is: five copies of it, joined by unknown springs.
+    fn unfold(&self) -> Row {
+        let mut springs = self.springs.clone();
+        for _ in 0..4 {
+            springs.push(Spring::Unknown);
+            springs.extend(&self.springs);
+        }
+        Row { springs, config: self.config.repeat(5) }
+    }
+}
+
+impl FromStr for Row {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (springs, config) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(line, line, "`<springs> <group sizes>`"))?;
+        let springs = springs
+            .char_indices()
+            .map(|(i, c)| {
+                Spring::try_from(c).map_err(|_| ParseError::at(line, &springs[i..i + c.len_utf8()], "., # or ?"))
+            })
+            .collect::<Result<_, _>>()?;
+        let config = config
+            .split(',')
+            .map(|size| field(line, size, "a group size"))
+            .collect::<Result<_, _>>()?;
+        Ok(Row { springs, config })
+    }
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Row>, ParseError> {
+    lines(inp)
+}
+
+#[aoc(test = "21", malformed = "???.### 1,1,3\n.??..??...?##. 1,x,3", malformed = "???.### 1,1,3\n???.#!# 1,1,3", answer = "7753", bench = 10, reference = reference::part_1)]
+pub fn part_1(rows: &[Row]) -> u64 {
+    let mut cache = HashMap::new();
+    let mut sum = 0;
+    for row in rows {
+        let mut spring = row.springs.clone();
+        let mut config = row.config.clone();
+        sum += count_permutations(&mut cache, &mut spring, &mut config, true, true)
+    }
+    sum
+}
+
+#[aoc(test = "525152", answer = "280382734828319", bench = 10, reference = reference::part_2)]
+pub fn part_2(rows: &[Row]) -> u64 {
+    let mut sum = 0;
+    let mut cache = HashMap::new();
+    for row in rows {
+        let Row { springs: mut spring, mut config } = row.unfold();
+        sum += count_permutations(&mut cache, &mut spring, &mut config, true, true)
+    }
+    sum
+}
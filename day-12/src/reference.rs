@@ -0,0 +1,66 @@
+//! Naive solvers that try every filling of the unknown springs, or run them through an automaton.
+
+fn rows(inp: &str) -> impl Iterator<Item = (&str, Vec<usize>)> {
+    inp.lines().map(|line| {
+        let (springs, groups) = line.split_once(' ').unwrap();
+        (springs, groups.split(',').map(|g| g.parse().unwrap()).collect())
+    })
+}
+
+/// The sizes of the groups of damaged springs in `springs`.
+fn groups(springs: &[u8]) -> Vec<usize> {
+    springs.split(|&s| s != b'#').map(<[u8]>::len).filter(|&len| len > 0).collect()
+}
+
+/// Tries every way of filling in the unknown springs.
+pub fn part_1(inp: &str) -> u64 {
+    rows(inp)
+        .map(|(springs, expected)| {
+            let unknown: Vec<usize> = (0..springs.len()).filter(|&i| springs.as_bytes()[i] == b'?').collect();
+            (0..1u64 << unknown.len())
+                .filter(|fill| {
+                    let mut springs = springs.as_bytes().to_vec();
+                    for (bit, &i) in unknown.iter().enumerate() {
+                        springs[i] = if fill >> bit & 1 == 1 { b'#' } else { b'.' };
+                    }
+                    groups(&springs) == expected
+                })
+                .count() as u64
+        })
+        .sum()
+}
+
+/// Counts the ways to fill in `springs` by running them through an automaton matching the groups,
+/// `.*#{a}.+#{b}.+ ... .*`, keeping how many fillings are in each of its states.
+fn arrangements(springs: &str, groups: &[usize]) -> u64 {
+    let mut pattern = vec![b'.'];
+    for &group in groups {
+        pattern.extend(std::iter::repeat_n(b'#', group));
+        pattern.push(b'.');
+    }
+    let mut counts = vec![0; pattern.len()];
+    counts[0] = 1;
+    for spring in springs.bytes() {
+        let mut next = vec![0; pattern.len()];
+        for (state, &count) in counts.iter().enumerate() {
+            let following = pattern.get(state + 1);
+            if spring != b'#' {
+                if pattern[state] == b'.' {
+                    next[state] += count;
+                }
+                if following == Some(&b'.') {
+                    next[state + 1] += count;
+                }
+            }
+            if spring != b'.' && following == Some(&b'#') {
+                next[state + 1] += count;
+            }
+        }
+        counts = next;
+    }
+    counts[pattern.len() - 1] + counts[pattern.len() - 2]
+}
+
+pub fn part_2(inp: &str) -> u64 {
+    rows(inp).map(|(springs, groups)| arrangements(&[springs; 5].join("?"), &groups.repeat(5))).sum()
+}
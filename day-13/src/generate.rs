@@ -0,0 +1,108 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+type Pattern = Vec<Vec<bool>>;
+
+fn transpose(pattern: &Pattern) -> Pattern {
+    (0..pattern[0].len()).map(|col| pattern.iter().map(|row| row[col]).collect()).collect()
+}
+
+/// For every horizontal line through `pattern`, the number of cells differing from their mirror
+/// image. Line `i` lies above row `i`.
+fn differences(pattern: &Pattern) -> Vec<(usize, usize)> {
+    (1..pattern.len())
+        .map(|line| {
+            let above = pattern[..line].iter().rev();
+            let below = pattern[line..].iter();
+            let differences = above.zip(below).map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b).count());
+            (line, differences.sum())
+        })
+        .collect()
+}
+
+/// Finds the root of `cell` in `parents`.
+fn root(parents: &mut [usize], cell: usize) -> usize {
+    match parents[cell] {
+        parent if parent == cell => cell,
+        parent => {
+            let root = root(parents, parent);
+            parents[cell] = root;
+            root
+        }
+    }
+}
+
+/// A pattern reflected in one line, with one cell off from being reflected in a line the other way,
+/// and no other line with at most one cell off.
+fn pattern(rng: &mut Rng) -> Pattern {
+    loop {
+        let (height, width) = (rng.usize(7..=17), rng.usize(7..=17));
+        // The horizontal line doesn't reach every row, leaving room for the smudge.
+        let line = loop {
+            let line = rng.usize(1..height);
+            if line * 2 != height {
+                break line;
+            }
+        };
+        let mirror = rng.usize(1..width);
+        let reach = line.min(height - line);
+        let reach_col = mirror.min(width - mirror);
+
+        // Cells that have to be the same to be reflected in both lines.
+        let mut parents: Vec<usize> = (0..height * width).collect();
+        for row in 0..height {
+            for col in 0..width {
+                let cell = row * width + col;
+                if (line - reach..line).contains(&row) {
+                    let other = (2 * line - 1 - row) * width + col;
+                    let (a, b) = (root(&mut parents, cell), root(&mut parents, other));
+                    parents[a] = b;
+                }
+                if (mirror - reach_col..mirror).contains(&col) {
+                    let other = row * width + 2 * mirror - 1 - col;
+                    let (a, b) = (root(&mut parents, cell), root(&mut parents, other));
+                    parents[a] = b;
+                }
+            }
+        }
+        let rocks: Vec<bool> = (0..height * width).map(|_| rng.bool()).collect();
+        let mut pattern: Pattern = (0..height)
+            .map(|row| (0..width).map(|col| rocks[root(&mut parents, row * width + col)]).collect())
+            .collect();
+
+        // The smudge, out of reach of the horizontal line and in reach of the vertical one.
+        let row = loop {
+            let row = rng.usize(..height);
+            if !(line - reach..line + reach).contains(&row) {
+                break row;
+            }
+        };
+        let col = rng.usize(mirror - reach_col..mirror + reach_col);
+        pattern[row][col] = !pattern[row][col];
+
+        let horizontal = differences(&pattern);
+        let vertical = differences(&transpose(&pattern));
+        let close = |lines: &[(usize, usize)]| lines.iter().filter(|&&(_, d)| d <= 1).copied().collect::<Vec<_>>();
+        if close(&horizontal) == [(line, 0)] && close(&vertical) == [(mirror, 1)] {
+            return match rng.bool() {
+                true => pattern,
+                false => transpose(&pattern),
+            };
+        }
+    }
+}
+
+/// `size` patterns of ash and rocks, each with one line of reflection and one smudge which, fixed,
+/// gives it another line of reflection.
+#[generator(size = 100, test_size = 20)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let rows = pattern(rng).into_iter();
+            rows.map(|row| row.into_iter().map(|rock| if rock { '#' } else { '.' }).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+    patterns.join("\n\n")
+}
@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use aoc_core::parse::ParseError;
+use aoc_grid::{Grid, RaggedRow};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+#[derive(Debug)]
+pub struct Mirror {
+    /// Each column, read top to bottom as a binary number.
+    pub verticals: Vec<u32>,
+    /// Each row, read left to right as a binary number.
+    pub horizontals: Vec<u32>,
+}
+
+/// Reads a row of rocks as a binary number.
+fn encode(row: &[bool]) -> u32 {
+    row.iter().fold(0, |acc, &rock| acc << 1 | rock as u32)
+}
+
+impl FromStr for Mirror {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse_with(value, |c| c == '#')
+            .map_err(|e: RaggedRow| ParseError::ragged(value, e.row, e.width))?;
+        Ok(Self {
+            horizontals: grid.rows().map(encode).collect(),
+            verticals: grid.transpose().rows().map(encode).collect(),
+        })
+    }
+}
+
+fn find_palindrome(items: &[u32]) -> Option<usize> {
+    let equal_x =
+        items
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, s)| if s[0] == s[1] { Some(i) } else { None });
+    for x in equal_x {
+        let mut i = x;
+        let mut j = x + 1;
+        loop {
+            if i == 0 || j == items.len() - 1 {
+                return Some(x);
+            }
+            i -= 1;
+            j += 1;
+            if items[i] != items[j] {
+                break;
+            }
+        }
+    }
+    None
+}
+
+fn find_smudgy_palindrome(items: &[u32]) -> Option<usize> {
+    for x in 0..items.len() - 1 {
+        let mut i = x;
+        let mut j = x + 1;
+        let mut smudge = false;
+        loop {
+            if items[i] != items[j] {
+                let diff = items[i] ^ items[j];
+                if !smudge && ((diff & (diff - 1)) == 0) {
+                    smudge = true;
+                } else {
+                    break;
+                }
+            }
+            if i == 0 || j == items.len() - 1 {
+                if !smudge {
+                    break;
+                }
+                return Some(x);
+            }
+            i -= 1;
+            j += 1;
+        }
+    }
+    None
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Mirror>, ParseError> {
+    inp.split("\n\n")
+        .map(|block| block.parse().map_err(|e: ParseError| e.inside(inp, block)))
+        .collect()
+}
+
+#[aoc(test = "405", malformed = "#.#\n.#\n\n##", answer = "37561", reference = reference::part_1)]
+pub fn part_1(mirrors: &[Mirror]) -> u32 {
+    let mut sum = 0;
+    for mirror in mirrors {
+        let horizontal = find_palindrome(&mirror.horizontals);
+        if let Some(x) = horizontal {
+            sum += (x + 1) * 100;
+            continue;
+        }
+        let vertical = find_palindrome(&mirror.verticals).unwrap();
+        sum += vertical + 1;
+    }
+    sum as u32
+}
+
+#[aoc(test = "400", answer = "31108", reference = reference::part_2)]
+pub fn part_2(mirrors: &[Mirror]) -> u32 {
+    let mut sum = 0;
+    for mirror in mirrors {
+        let horizontal = find_smudgy_palindrome(&mirror.horizontals);
+        if let Some(x) = horizontal {
+            sum += (x + 1) * 100;
+            continue;
+        }
+        let vertical = find_smudgy_palindrome(&mirror.verticals).unwrap();
+        sum += vertical + 1;
+    }
+    sum as u32
+}
@@ -0,0 +1,48 @@
+//! Naive solvers that try every line of reflection, and every smudge in turn for part 2.
+
+/// Every line of reflection of `pattern`, scored as in the puzzle: the columns to the left of a
+/// vertical line, or 100 times the rows above a horizontal one.
+fn reflections(pattern: &[Vec<u8>]) -> Vec<usize> {
+    let (height, width) = (pattern.len(), pattern[0].len());
+    let mut lines = Vec::new();
+    for row in 1..height {
+        if (0..row.min(height - row)).all(|i| pattern[row - 1 - i] == pattern[row + i]) {
+            lines.push(100 * row);
+        }
+    }
+    for col in 1..width {
+        let mirrored = |line: &Vec<u8>| (0..col.min(width - col)).all(|i| line[col - 1 - i] == line[col + i]);
+        if pattern.iter().all(mirrored) {
+            lines.push(col);
+        }
+    }
+    lines
+}
+
+fn patterns(inp: &str) -> impl Iterator<Item = Vec<Vec<u8>>> + '_ {
+    inp.split("\n\n").map(|pattern| pattern.lines().map(|line| line.bytes().collect()).collect())
+}
+
+pub fn part_1(inp: &str) -> usize {
+    patterns(inp).map(|pattern| reflections(&pattern)[0]).sum()
+}
+
+/// Fixes every place in turn until a line of reflection other than the old one shows up.
+pub fn part_2(inp: &str) -> usize {
+    patterns(inp)
+        .map(|mut pattern| {
+            let old = reflections(&pattern)[0];
+            for row in 0..pattern.len() {
+                for col in 0..pattern[0].len() {
+                    let original = pattern[row][col];
+                    pattern[row][col] = if original == b'#' { b'.' } else { b'#' };
+                    if let Some(&line) = reflections(&pattern).iter().find(|&&line| line != old) {
+                        return line;
+                    }
+                    pattern[row][col] = original;
+                }
+            }
+            panic!("no smudge gives a new line of reflection")
+        })
+        .sum()
+}
@@ -0,0 +1,21 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// A `size` by `size` platform with a round rock on about a fifth of the places and a cube-shaped
+/// rock on about a tenth.
+#[generator(size = 100, test_size = 15)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.u8(0..10) {
+                    0 | 1 => 'O',
+                    2 => '#',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
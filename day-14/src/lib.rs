@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use aoc_core::{parse::ParseError, trace};
+use aoc_grid::{Grid, Point, RaggedRow};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+pub mod render;
+
+#[derive(Hash, PartialEq, Eq, Clone)]
+pub enum Rock {
+    None,
+    Circle,
+    Square,
+}
+
+impl From<char> for Rock {
+    fn from(value: char) -> Self {
+        match value {
+            'O' => Rock::Circle,
+            '#' => Rock::Square,
+            _ => Rock::None,
+        }
+    }
+}
+
+/// Rolls every round rock as far up as it goes.
+fn north(grid: &mut Grid<Rock>) {
+    for col in 0..grid.width() {
+        let mut height = 0;
+        for row in 0..grid.height() {
+            match grid[Point::new(row, col)] {
+                Rock::Circle => {
+                    if row != height {
+                        grid[Point::new(height, col)] = Rock::Circle;
+                        grid[Point::new(row, col)] = Rock::None;
+                    }
+                    height += 1;
+                }
+                Rock::Square => {
+                    height = row + 1;
+                }
+                Rock::None => continue,
+            }
+        }
+    }
+}
+
+/// Tilts north, west, south and east, by tilting north and rotating the west side up each time.
+fn spin_cycle(grid: &Grid<Rock>) -> Grid<Rock> {
+    let mut grid = grid.clone();
+    for _ in 0..4 {
+        north(&mut grid);
+        grid = grid.rotate_clockwise();
+    }
+    grid
+}
+
+fn count_north(grid: &Grid<Rock>) -> u32 {
+    grid.rows()
+        .enumerate()
+        .map(|(row, rocks)| rocks.iter().filter(|r| **r == Rock::Circle).count() * (grid.height() - row))
+        .sum::<usize>() as u32
+}
+
+pub fn parse(inp: &str) -> Result<Grid<Rock>, ParseError> {
+    inp.parse().map_err(|e: RaggedRow| ParseError::ragged(inp, e.row, e.width))
+}
+
+#[aoc(test = "136", malformed = "O.#\n.O", answer = "109833", bench, reference = reference::part_1, render = render::part_1)]
+pub fn part_1(grid: &Grid<Rock>) -> u32 {
+    let mut grid = grid.clone();
+    north(&mut grid);
+    count_north(&grid)
+}
+
+#[aoc(test = "64", answer = "99875", bench, reference = reference::part_2, render = render::part_2)]
+pub fn part_2(grid: &Grid<Rock>) -> u32 {
+    let mut grid = grid.clone();
+    let mut it = 0;
+    let mut cache: HashMap<Grid<Rock>, u64> = HashMap::new();
+    while it < 1000000000 {
+        grid = spin_cycle(&grid);
+        it += 1;
+        trace!("spin", cycle = it, load = count_north(&grid));
+        if let Some(x) = cache.insert(grid.clone(), it) {
+            let cycle = it - x;
+            let skipped = (1000000000 - it) / cycle;
+            trace!("repeat", cycle = it, first = x, length = cycle, skipped = skipped * cycle);
+            it += skipped * cycle;
+            // Less than a cycle is left, which the grids seen so far would only skip past.
+            cache.clear();
+        }
+    }
+    count_north(&grid)
+}
@@ -0,0 +1,56 @@
+//! Naive solvers that move round rocks one place at a time and spin until the platform repeats.
+
+type Platform = Vec<Vec<u8>>;
+
+/// Moves round rocks one place towards `(dr, dc)` at a time, until none can move.
+fn tilt(platform: &mut Platform, (dr, dc): (isize, isize)) {
+    let (height, width) = (platform.len() as isize, platform[0].len() as isize);
+    let mut moved = true;
+    while moved {
+        moved = false;
+        for row in 0..height {
+            for col in 0..width {
+                let (to_row, to_col) = (row + dr, col + dc);
+                if platform[row as usize][col as usize] == b'O'
+                    && (0..height).contains(&to_row)
+                    && (0..width).contains(&to_col)
+                    && platform[to_row as usize][to_col as usize] == b'.'
+                {
+                    platform[to_row as usize][to_col as usize] = b'O';
+                    platform[row as usize][col as usize] = b'.';
+                    moved = true;
+                }
+            }
+        }
+    }
+}
+
+fn load(platform: &Platform) -> usize {
+    (0..platform.len()).map(|row| (platform.len() - row) * platform[row].iter().filter(|&&c| c == b'O').count()).sum()
+}
+
+fn platform(inp: &str) -> Platform {
+    inp.lines().map(|line| line.bytes().collect()).collect()
+}
+
+pub fn part_1(inp: &str) -> usize {
+    let mut platform = platform(inp);
+    tilt(&mut platform, (-1, 0));
+    load(&platform)
+}
+
+/// Spins until the platform repeats one it was in before, and takes the billionth from the cycle.
+pub fn part_2(inp: &str) -> usize {
+    let mut seen = vec![platform(inp)];
+    loop {
+        let mut platform = seen[seen.len() - 1].clone();
+        for direction in [(-1, 0), (0, -1), (1, 0), (0, 1)] {
+            tilt(&mut platform, direction);
+        }
+        if let Some(start) = seen.iter().position(|p| *p == platform) {
+            let cycle = seen.len() - start;
+            return load(&seen[start + (1_000_000_000 - start) % cycle]);
+        }
+        seen.push(platform);
+    }
+}
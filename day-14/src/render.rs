@@ -0,0 +1,38 @@
+//! Pictures of what the solvers work out, for `aoc run --render`.
+
+use std::collections::HashSet;
+
+use aoc_grid::{
+    render::{Frame, Pixel, Rgb},
+    Grid,
+};
+
+use crate::{north, spin_cycle, Rock};
+
+fn draw(grid: &Grid<Rock>) -> Frame {
+    grid.map(|rock| match rock {
+        Rock::Circle => Pixel::new('O', Rgb::ORANGE),
+        Rock::Square => Pixel::new('#', Rgb::GREY),
+        Rock::None => Pixel::new('.', Rgb::DARK),
+    })
+}
+
+/// The platform before and after tilting it north.
+pub fn part_1(grid: &Grid<Rock>) -> Vec<Frame> {
+    let mut tilted = grid.clone();
+    north(&mut tilted);
+    vec![draw(grid), draw(&tilted)]
+}
+
+/// The platform after every spin cycle, until it gets back to a position it was in before.
+pub fn part_2(grid: &Grid<Rock>) -> Vec<Frame> {
+    let mut frames = vec![draw(grid)];
+    let mut seen = HashSet::from([grid.clone()]);
+    let mut grid = spin_cycle(grid);
+    while seen.insert(grid.clone()) {
+        frames.push(draw(&grid));
+        grid = spin_cycle(&grid);
+    }
+    frames.push(draw(&grid));
+    frames
+}
@@ -0,0 +1,21 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// `size` steps on a few hundred labels of one to six letters, each setting a focal length from 1
+/// to 9 or, about one time in three, removing the lens.
+#[generator(size = 4000, test_size = 100)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..rng.usize(100..500))
+        .map(|_| (0..rng.usize(1..=6)).map(|_| rng.lowercase()).collect())
+        .collect();
+    let steps: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let label = &labels[rng.usize(..labels.len())];
+            match rng.u8(0..3) {
+                0 => format!("{}-", label),
+                _ => format!("{}={}", label, rng.u8(1..=9)),
+            }
+        })
+        .collect();
+    steps.join(",")
+}
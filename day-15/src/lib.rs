@@ -0,0 +1,107 @@
+use std::str::FromStr;
+
+use aoc_core::parse::{field, ParseError};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+fn hash(inp: &str) -> u32 {
+    inp.chars()
+        .fold(0_u32, |acc, c| ((acc + (c as u32)) * 17) % 256)
+}
+
+pub enum CommandType {
+    Set(u32),
+    Remove,
+}
+
+impl FromStr for CommandType {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.chars().next() {
+            Some('=') => Ok(CommandType::Set(field(value, &value[1..], "a focal length")?)),
+            Some('-') if value.len() == 1 => Ok(CommandType::Remove),
+            _ => Err(ParseError::at(value, value, "`=<focal length>` or `-`")),
+        }
+    }
+}
+
+pub struct Command {
+    pub label: String,
+    pub ty: CommandType,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let split = value.find(|x: char| !x.is_alphabetic()).unwrap_or(value.len());
+        let (label, ty) = value.split_at(split);
+        Ok(Self {
+            label: label.to_string(),
+            ty: ty.parse().map_err(|e: ParseError| e.inside(value, ty))?,
+        })
+    }
+}
+
+pub struct Sequence {
+    /// The steps as written, which is what the hash is taken of.
+    pub steps: Vec<String>,
+    pub commands: Vec<Command>,
+}
+
+pub fn parse(inp: &str) -> Result<Sequence, ParseError> {
+    let steps: Vec<&str> = inp.split(',').collect();
+    let commands = steps
+        .iter()
+        .map(|command| command.parse().map_err(|e: ParseError| e.inside(inp, command)))
+        .collect::<Result<_, _>>()?;
+    Ok(Sequence { steps: steps.into_iter().map(str::to_string).collect(), commands })
+}
+
+#[aoc(test = "1320", answer = "507769", reference = reference::part_1)]
+pub fn part_1(sequence: &Sequence) -> u32 {
+    sequence.steps.iter().map(|step| hash(step)).sum()
+}
+
+#[aoc(test = "145", malformed = "rn=1,cm-,qp=x", malformed = "rn=1,cm", answer = "269747", reference = reference::part_2)]
+pub fn part_2(sequence: &Sequence) -> u32 {
+    let mut map: Vec<Vec<(String, u32)>> = vec![Vec::new(); 256];
+    for command in &sequence.commands {
+        let box_id = hash(&command.label);
+        match command.ty {
+            CommandType::Set(number) => {
+                let bx = &mut map[box_id as usize];
+                let mut found = false;
+                for b in bx.iter_mut() {
+                    if b.0 == command.label {
+                        b.1 = number;
+                        found = true;
+                    }
+                }
+                if !found {
+                    bx.push((command.label.clone(), number))
+                }
+            }
+            CommandType::Remove => {
+                let index = map[box_id as usize]
+                    .iter()
+                    .position(|x| x.0 == command.label);
+                if let Some(x) = index {
+                    map[box_id as usize].remove(x);
+                }
+            }
+        }
+    }
+    map.iter()
+        .enumerate()
+        .map(|(i, x)| {
+            x.iter()
+                .enumerate()
+                .map(|(j, y)| y.1 * (i + 1) as u32 * (j + 1) as u32)
+                .sum::<u32>()
+        })
+        .sum()
+}
@@ -0,0 +1,36 @@
+//! Naive solvers that keep every lens in one list and sort out the boxes at the end.
+
+fn hash(s: &str) -> usize {
+    let mut value = 0;
+    for c in s.bytes() {
+        value = (value + c as usize) * 17 % 256;
+    }
+    value
+}
+
+pub fn part_1(inp: &str) -> usize {
+    inp.split(',').map(hash).sum()
+}
+
+/// Keeps every lens in one list in the order it went in, working out boxes and slots at the end.
+pub fn part_2(inp: &str) -> usize {
+    let mut lenses: Vec<(&str, usize)> = Vec::new();
+    for step in inp.split(',') {
+        if let Some(label) = step.strip_suffix('-') {
+            lenses.retain(|lens| lens.0 != label);
+        } else {
+            let (label, focal) = step.split_once('=').unwrap();
+            let focal = focal.parse().unwrap();
+            match lenses.iter_mut().find(|lens| lens.0 == label) {
+                Some(lens) => lens.1 = focal,
+                None => lenses.push((label, focal)),
+            }
+        }
+    }
+    let mut power = 0;
+    for (i, &(label, focal)) in lenses.iter().enumerate() {
+        let slot = lenses[..i].iter().filter(|lens| hash(lens.0) == hash(label)).count() + 1;
+        power += (hash(label) + 1) * slot * focal;
+    }
+    power
+}
@@ -0,0 +1,19 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// A `size` by `size` contraption with a mirror or splitter on about one in ten tiles.
+#[generator(size = 110, test_size = 15)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.u8(0..10) {
+                    0 => b"-|/\\"[rng.usize(..4)] as char,
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
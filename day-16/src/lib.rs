@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use aoc_core::parse::ParseError;
+use aoc_graph::{reachable, Graph};
+use aoc_grid::{
+    Direction::{self, *},
+    Grid, Point, RaggedRow,
+};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+pub mod render;
+
+type State = (Point, Direction);
+
+pub enum Tile {
+    Empty,
+    HorizontalSplit,
+    VerticalSplit,
+    RightMirror,
+    LeftMirror,
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '-' => Tile::HorizontalSplit,
+            '|' => Tile::VerticalSplit,
+            '/' => Tile::RightMirror,
+            '\\' => Tile::LeftMirror,
+            _ => Tile::Empty,
+        }
+    }
+}
+
+pub struct Map(pub Grid<Tile>);
+
+impl Map {
+    fn interact(&self, (point, dir): State) -> Vec<State> {
+        let new_dirs = match self.0[point] {
+            Tile::Empty => vec![dir],
+            Tile::HorizontalSplit => match dir {
+                Left | Right => vec![dir],
+                Up | Down => vec![Left, Right],
+            },
+            Tile::VerticalSplit => match dir {
+                Left | Right => vec![Up, Down],
+                Up | Down => vec![dir],
+            },
+            Tile::RightMirror => match dir {
+                Left => vec![Down],
+                Right => vec![Up],
+                Up => vec![Right],
+                Down => vec![Left],
+            },
+            Tile::LeftMirror => match dir {
+                Left => vec![Up],
+                Right => vec![Down],
+                Up => vec![Left],
+                Down => vec![Right],
+            },
+        };
+        new_dirs
+            .into_iter()
+            .filter_map(|dir| Some((self.0.step(point, dir)?, dir)))
+            .collect()
+    }
+
+    /// The tiles a beam entering at `start` passes through.
+    fn energized(&self, start: State) -> HashSet<Point> {
+        reachable(self, [start]).into_iter().map(|(point, _)| point).collect()
+    }
+
+    fn simulate_beam(&self, start: State) -> u32 {
+        self.energized(start).len() as u32
+    }
+
+    /// Every way a beam can enter from the edge.
+    fn edge_starts(&self) -> Vec<State> {
+        let (width, height) = (self.0.width(), self.0.height());
+        let mut states = Vec::new();
+        for i in 0..width {
+            states.push((Point::new(0, i), Down));
+            states.push((Point::new(height - 1, i), Up));
+        }
+        for i in 0..height {
+            states.push((Point::new(i, 0), Right));
+            states.push((Point::new(i, width - 1), Left));
+        }
+        states
+    }
+}
+
+impl Graph for Map {
+    type Node = State;
+
+    fn neighbours(&self, &state: &State) -> impl IntoIterator<Item = State> {
+        self.interact(state)
+    }
+}
+
+pub fn parse(inp: &str) -> Result<Map, ParseError> {
+    let tiles: Grid<Tile> = inp.parse().map_err(|e: RaggedRow| ParseError::ragged(inp, e.row, e.width))?;
+    if tiles.width() == 0 || tiles.height() == 0 {
+        return Err(ParseError::end(inp, "a row of tiles"));
+    }
+    Ok(Map(tiles))
+}
+
+#[aoc(test = "46", malformed = ".|.\n..-..", malformed = "", answer = "8021", reference = reference::part_1, render = render::part_1)]
+pub fn part_1(map: &Map) -> u32 {
+    map.simulate_beam((Point::new(0, 0), Right))
+}
+
+#[aoc(test = "51", answer = "8216", reference = reference::part_2, render = render::part_2)]
+pub fn part_2(map: &Map) -> u32 {
+    map.edge_starts()
+        .into_iter()
+        .map(|s| map.simulate_beam(s))
+        .max()
+        .unwrap()
+}
@@ -0,0 +1,61 @@
+//! Naive solvers that sweep the whole grid for beams until nothing new gets energized.
+
+/// `(row, col)` steps of the directions up, right, down and left.
+const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// The directions a beam heading in `dir` leaves `tile` in.
+fn bounce(tile: u8, dir: usize) -> Vec<usize> {
+    match (tile, dir) {
+        (b'/', _) => vec![[1, 0, 3, 2][dir]],
+        (b'\\', _) => vec![[3, 2, 1, 0][dir]],
+        (b'|', 1 | 3) => vec![0, 2],
+        (b'-', 0 | 2) => vec![1, 3],
+        _ => vec![dir],
+    }
+}
+
+/// Marks where beams are and where they head until nothing new gets marked, sweeping over the
+/// whole grid each time.
+fn energized(grid: &[&[u8]], start: (usize, usize, usize)) -> usize {
+    let (height, width) = (grid.len(), grid[0].len());
+    let mut beams = vec![vec![[false; 4]; width]; height];
+    beams[start.0][start.1][start.2] = true;
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for row in 0..height {
+            for col in 0..width {
+                let heading = beams[row][col];
+                for dir in (0..4).filter(|&dir| heading[dir]) {
+                    for out in bounce(grid[row][col], dir) {
+                        let (r, c) = (row as isize + STEPS[out].0, col as isize + STEPS[out].1);
+                        if (0..height as isize).contains(&r) && (0..width as isize).contains(&c) {
+                            let beam = &mut beams[r as usize][c as usize][out];
+                            changed |= !*beam;
+                            *beam = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    beams.iter().flatten().filter(|tile| tile.contains(&true)).count()
+}
+
+pub fn part_1(inp: &str) -> usize {
+    let grid: Vec<&[u8]> = inp.lines().map(str::as_bytes).collect();
+    energized(&grid, (0, 0, 1))
+}
+
+pub fn part_2(inp: &str) -> usize {
+    let grid: Vec<&[u8]> = inp.lines().map(str::as_bytes).collect();
+    let (height, width) = (grid.len(), grid[0].len());
+    let mut starts = Vec::new();
+    for col in 0..width {
+        starts.extend([(0, col, 2), (height - 1, col, 0)]);
+    }
+    for row in 0..height {
+        starts.extend([(row, 0, 1), (row, width - 1, 3)]);
+    }
+    starts.into_iter().map(|start| energized(&grid, start)).max().unwrap()
+}
@@ -0,0 +1,67 @@
+//! Pictures of what the solvers work out, for `aoc run --render`.
+
+use std::collections::HashSet;
+
+use aoc_grid::{
+    render::{Frame, Pixel, Rgb},
+    Direction, Grid, Point,
+};
+
+use crate::{Map, Tile};
+
+fn symbol(tile: &Tile) -> char {
+    match tile {
+        Tile::Empty => '.',
+        Tile::HorizontalSplit => '-',
+        Tile::VerticalSplit => '|',
+        Tile::RightMirror => '/',
+        Tile::LeftMirror => '\\',
+    }
+}
+
+/// The energized tiles lit up, empty ones as `#`.
+fn draw(map: &Map, energized: &Grid<bool>) -> Frame {
+    let mut frame = map.0.map(|tile| Pixel::new(symbol(tile), Rgb::GREY));
+    for p in map.0.points() {
+        if energized[p] {
+            let ch = match map.0[p] {
+                Tile::Empty => '#',
+                ref tile => symbol(tile),
+            };
+            frame[p] = Pixel::new(ch, Rgb::YELLOW);
+        } else if let Tile::Empty = map.0[p] {
+            frame[p].color = Rgb::DARK;
+        }
+    }
+    frame
+}
+
+/// The beam spreading from the top left corner, a frame for every step it takes.
+pub fn part_1(map: &Map) -> Vec<Frame> {
+    let mut energized = Grid::new(map.0.width(), map.0.height(), false);
+    let mut seen = HashSet::new();
+    let mut front = vec![(Point::new(0, 0), Direction::Right)];
+    let mut frames = Vec::new();
+    while !front.is_empty() {
+        for &(p, _) in &front {
+            energized[p] = true;
+        }
+        seen.extend(front.iter().copied());
+        frames.push(draw(map, &energized));
+        let next: HashSet<_> =
+            front.iter().flat_map(|&state| map.interact(state)).filter(|s| !seen.contains(s)).collect();
+        front = next.into_iter().collect();
+    }
+    frames
+}
+
+/// The tiles energized by the beam entering where it energizes the most.
+pub fn part_2(map: &Map) -> Vec<Frame> {
+    let starts = map.edge_starts();
+    let best = starts.into_iter().map(|start| map.energized(start)).max_by_key(HashSet::len).unwrap();
+    let mut energized = Grid::new(map.0.width(), map.0.height(), false);
+    for p in best {
+        energized[p] = true;
+    }
+    vec![draw(map, &energized)]
+}
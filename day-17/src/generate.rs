@@ -0,0 +1,11 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// A `size` by `size` map of heat losses from 1 to 9. At least four blocks wide, so an ultra
+/// crucible can get to the end.
+#[generator(size = 141, test_size = 15)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5);
+    let rows: Vec<String> = (0..size).map(|_| (0..size).map(|_| rng.char('1'..='9')).collect()).collect();
+    rows.join("\n")
+}
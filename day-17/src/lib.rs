@@ -0,0 +1,71 @@
+use aoc_core::parse::ParseError;
+use aoc_graph::{astar_path, WeightedGraph};
+use aoc_grid::{Direction, Grid, Point, RaggedRow};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+pub mod render;
+
+/// A crucible on the map, which has to go between `min` and `max` blocks straight before turning.
+struct Crucible<'a> {
+    map: &'a Grid<u32>,
+    min: usize,
+    max: usize,
+}
+
+impl WeightedGraph for Crucible<'_> {
+    /// Where the crucible is, and the direction it goes next.
+    type Node = (Point, Direction);
+    type Weight = u32;
+
+    fn edges(&self, &(p, dir): &Self::Node) -> impl IntoIterator<Item = (Self::Node, u32)> {
+        std::iter::successors(Some(p), move |&p| self.map.step(p, dir))
+            .skip(1)
+            .take(self.max)
+            .scan(0, |heat, n| {
+                *heat += self.map[n];
+                Some((n, *heat))
+            })
+            .skip(self.min - 1)
+            .flat_map(move |(n, heat)| dir.perpendicular().map(|new_dir| ((n, new_dir), heat)))
+    }
+}
+
+/// The blocks the crucible turns at on the way with the least heat loss, each with the direction
+/// it leaves in, and that heat loss.
+fn best_route(map: &Grid<u32>, part_2: bool) -> (Vec<(Point, Direction)>, u32) {
+    let end = Point::new(map.height() - 1, map.width() - 1);
+    let (min, max) = if part_2 { (4, 10) } else { (1, 3) };
+    let crucible = Crucible { map, min, max };
+    let start = Point::new(0, 0);
+    astar_path(
+        &crucible,
+        [(start, Direction::Right), (start, Direction::Down)],
+        |&(p, _)| p == end,
+        |&(p, _)| p.manhattan(end) as u32,
+    )
+    .unwrap()
+}
+
+fn shortest_path(map: &Grid<u32>, part_2: bool) -> u32 {
+    best_route(map, part_2).1
+}
+
+pub fn parse(inp: &str) -> Result<Grid<u32>, ParseError> {
+    let map: Grid<char> = inp.parse().map_err(|e: RaggedRow| ParseError::ragged(inp, e.row, e.width))?;
+    if let Some(p) = map.position(|c| !c.is_ascii_digit()) {
+        return Err(ParseError::cell(inp, p.row, p.col, "a heat loss digit"));
+    }
+    Ok(map.map(|&c| c as u32 - '0' as u32))
+}
+
+#[aoc(test = "102", malformed = "241\n32", malformed = "241\n3x2", answer = "1128", reference = reference::part_1, render = render::part_1)]
+pub fn part_1(map: &Grid<u32>) -> u32 {
+    shortest_path(map, false)
+}
+
+#[aoc(test = "94", answer = "1268", reference = reference::part_2, render = render::part_2)]
+pub fn part_2(map: &Grid<u32>) -> u32 {
+    shortest_path(map, true)
+}
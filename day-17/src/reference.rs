@@ -0,0 +1,60 @@
+//! Naive solvers that relax every block, direction and run until no heat loss improves.
+
+/// `(row, col)` steps of the directions up, right, down and left.
+const STEPS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Relaxes the least heat loss for every block, direction and number of blocks gone straight
+/// until nothing improves, moving a single block at a time.
+fn least_heat_loss(inp: &str, min: usize, max: usize) -> u32 {
+    let map: Vec<Vec<u32>> = inp.lines().map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect()).collect();
+    let (height, width) = (map.len(), map[0].len());
+    let mut loss = vec![vec![vec![vec![u32::MAX; max + 1]; 4]; width]; height];
+    let step = |row: usize, col: usize, (dr, dc): (isize, isize)| {
+        let (r, c) = (row as isize + dr, col as isize + dc);
+        ((0..height as isize).contains(&r) && (0..width as isize).contains(&c)).then_some((r as usize, c as usize))
+    };
+    for dir in [1, 2] {
+        if let Some((r, c)) = step(0, 0, STEPS[dir]) {
+            loss[r][c][dir][1] = map[r][c];
+        }
+    }
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for row in 0..height {
+            for col in 0..width {
+                for dir in 0..4 {
+                    for run in 1..=max {
+                        let here = loss[row][col][dir][run];
+                        if here == u32::MAX {
+                            continue;
+                        }
+                        for (next, &offset) in STEPS.iter().enumerate() {
+                            let next_run = if next == dir { run + 1 } else { 1 };
+                            let turns = next != dir && next != (dir + 2) % 4;
+                            if (next == dir && run < max) || (turns && run >= min) {
+                                if let Some((r, c)) = step(row, col, offset) {
+                                    let best = &mut loss[r][c][next][next_run];
+                                    if here + map[r][c] < *best {
+                                        *best = here + map[r][c];
+                                        changed = true;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let end = &loss[height - 1][width - 1];
+    end.iter().flat_map(|runs| &runs[min..]).copied().min().unwrap()
+}
+
+pub fn part_1(inp: &str) -> u32 {
+    least_heat_loss(inp, 1, 3)
+}
+
+pub fn part_2(inp: &str) -> u32 {
+    least_heat_loss(inp, 4, 10)
+}
@@ -0,0 +1,38 @@
+//! Pictures of what the solvers work out, for `aoc run --render`.
+
+use aoc_grid::{
+    render::{Frame, Pixel, Rgb},
+    Direction, Grid,
+};
+
+use crate::best_route;
+
+/// The heat loss of every block, brighter for more, and the route with the least as red arrows.
+fn draw(map: &Grid<u32>, part_2: bool) -> Frame {
+    let mut frame = map.map(|&heat| {
+        Pixel::new(char::from_digit(heat, 10).unwrap(), Rgb::DARK.mix(Rgb::WHITE, heat as f64 / 9.0))
+    });
+    let (route, _) = best_route(map, part_2);
+    for turn in route.windows(2) {
+        let ((mut p, dir), (to, _)) = (turn[0], turn[1]);
+        let arrow = match dir {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+        while p != to {
+            p = map.step(p, dir).unwrap();
+            frame[p] = Pixel::new(arrow, Rgb::RED);
+        }
+    }
+    frame
+}
+
+pub fn part_1(map: &Grid<u32>) -> Vec<Frame> {
+    vec![draw(map, false)]
+}
+
+pub fn part_2(map: &Grid<u32>) -> Vec<Frame> {
+    vec![draw(map, true)]
+}
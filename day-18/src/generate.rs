@@ -0,0 +1,74 @@
+use aoc_core::generate::Rng;
+use aoc_grid::Direction::{self, *};
+use aoc_macros::generator;
+
+/// The outline of `bands` bands stacked on top of each other, each overlapping the one above and
+/// with other ends than it, so every band adds a corner on either side. Starts at the top left
+/// going right, and takes steps of up to `scale`.
+fn outline(rng: &mut Rng, bands: usize, scale: i64) -> Vec<(Direction, i64)> {
+    let mut ends = vec![(0, rng.i64(1..=scale))];
+    for _ in 1..bands {
+        let (left, right) = *ends.last().unwrap();
+        let new_left = loop {
+            let l = rng.i64(left - scale..right);
+            if l != left {
+                break l;
+            }
+        };
+        let new_right = loop {
+            let r = rng.i64(new_left.max(left) + 1..=right + scale);
+            if r != right {
+                break r;
+            }
+        };
+        ends.push((new_left, new_right));
+    }
+    let heights: Vec<i64> = (0..bands).map(|_| rng.i64(1..=scale)).collect();
+    let across = |from: i64, to: i64| (if to > from { Right } else { Left }, (to - from).abs());
+
+    let (left, right) = ends[0];
+    let mut steps = vec![(Right, right - left)];
+    for (i, w) in ends.windows(2).enumerate() {
+        steps.push((Down, heights[i]));
+        steps.push(across(w[0].1, w[1].1));
+    }
+    steps.push((Down, heights[bands - 1]));
+    let (left, right) = ends[bands - 1];
+    steps.push((Left, right - left));
+    for i in (1..bands).rev() {
+        steps.push((Up, heights[i]));
+        steps.push(across(ends[i].0, ends[i - 1].0));
+    }
+    steps.push((Up, heights[0]));
+    steps
+}
+
+/// A dig plan of `size` instructions, rounded down to a multiple of four. The instructions and the
+/// ones hidden in the colors each dig out a lagoon without crossing themselves, steps of up to 10
+/// and 200000 meters.
+#[generator(size = 700, test_size = 40)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let bands = (size / 4).max(1);
+    let plain = outline(rng, bands, 10);
+    let color = outline(rng, bands, 200_000);
+    let lines: Vec<String> = plain
+        .into_iter()
+        .zip(color)
+        .map(|((dir, len), (color_dir, color_len))| {
+            let dir = match dir {
+                Up => 'U',
+                Right => 'R',
+                Down => 'D',
+                Left => 'L',
+            };
+            let color_dir = match color_dir {
+                Right => 0,
+                Down => 1,
+                Left => 2,
+                Up => 3,
+            };
+            format!("{} {} (#{:05x}{})", dir, len, color_len, color_dir)
+        })
+        .collect();
+    lines.join("\n")
+}
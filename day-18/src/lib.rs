@@ -0,0 +1,99 @@
+use std::str::FromStr;
+
+use aoc_core::parse::{field, lines, ParseError};
+use aoc_grid::Direction;
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+type Scale = i64;
+
+// Using Pick's formula and shoelace formula
+fn calculate_area(vertices: &[(Scale, Scale)]) -> Scale {
+    let mut area: i64 = 0;
+    let mut last = &(0, 0);
+    let mut b = 0;
+    for next in vertices.iter().skip(1) {
+        area += (last.0 + next.0) * (last.1 - next.1);
+        b += ((next.0 - last.0) + (next.1 - last.1)).abs();
+        last = next;
+    }
+    area += (last.0) * (last.1);
+    b += last.0 + last.1;
+    (area / 2) + (b / 2) + 1
+}
+
+pub struct Instruction {
+    pub dir: Direction,
+    pub len: Scale,
+}
+
+/// A line of the dig plan: the instruction as written, and the one hidden in its color.
+pub struct Step {
+    pub plain: Instruction,
+    pub color: Instruction,
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut fields = line.split(' ');
+        let (Some(dir), Some(len), Some(color), None) = (fields.next(), fields.next(), fields.next(), fields.next()) else {
+            return Err(ParseError::at(line, line, "`<direction> <length> (#<color>)`"));
+        };
+        let dir = match dir {
+            "U" => Direction::Up,
+            "R" => Direction::Right,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            _ => return Err(ParseError::at(line, dir, "U, R, D or L")),
+        };
+        let len = field(line, len, "a length")?;
+        let hex = color
+            .strip_prefix("(#")
+            .and_then(|c| c.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.is_ascii())
+            .ok_or_else(|| ParseError::at(line, color, "`(#<six hex digits>)`"))?;
+        let (hex_len, hex_dir) = hex.split_at(5);
+        let color_len = i64::from_str_radix(hex_len, 16).map_err(|_| ParseError::at(line, hex_len, "five hex digits"))?;
+        let color_dir = match hex_dir {
+            "3" => Direction::Up,
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            _ => return Err(ParseError::at(line, hex_dir, "0, 1, 2 or 3")),
+        };
+        Ok(Step {
+            plain: Instruction { dir, len },
+            color: Instruction { dir: color_dir, len: color_len },
+        })
+    }
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Step>, ParseError> {
+    lines(inp)
+}
+
+/// The area of the lagoon dug by following `instructions`.
+fn dig<'a>(instructions: impl Iterator<Item = &'a Instruction>) -> Scale {
+    let mut vertices = Vec::new();
+    let (mut y, mut x) = (0, 0);
+    for Instruction { dir, len } in instructions {
+        vertices.push((y, x));
+        let (dy, dx) = dir.offset();
+        (y, x) = (y + dy as Scale * len, x + dx as Scale * len);
+    }
+    calculate_area(&vertices)
+}
+
+#[aoc(test = "62", malformed = "R 6 (#70c710)\nU 4 (#70c71)", malformed = "X 6 (#70c710)", answer = "53300", reference = reference::part_1)]
+pub fn part_1(steps: &[Step]) -> Scale {
+    dig(steps.iter().map(|step| &step.plain))
+}
+
+#[aoc(test = "952408144115", answer = "64294334780659", reference = reference::part_2)]
+pub fn part_2(steps: &[Step]) -> Scale {
+    dig(steps.iter().map(|step| &step.color))
+}
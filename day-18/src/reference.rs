@@ -0,0 +1,80 @@
+//! Naive solvers that flood fill a squeezed grid of the trench instead of taking its area.
+
+use std::collections::BTreeSet;
+
+/// Digs the trench on a grid squeezed down to the coordinates where it turns, with a cell for each
+/// corner and one for each run of meters between corners, then flood fills the ground outside it.
+/// The lagoon is everything the flood doesn't reach.
+fn dig(plan: &[(char, i64)]) -> i64 {
+    let mut corners = vec![(0, 0)];
+    for &(dir, len) in plan {
+        let (y, x) = *corners.last().unwrap();
+        corners.push(match dir {
+            'U' => (y - len, x),
+            'R' => (y, x + len),
+            'D' => (y + len, x),
+            _ => (y, x - len),
+        });
+    }
+    // Each cell starts at one coordinate and ends before the next, with a ring of ground around.
+    let bounds = |coord: fn(&(i64, i64)) -> i64| {
+        let mut bounds: BTreeSet<i64> = corners.iter().flat_map(|c| [coord(c), coord(c) + 1]).collect();
+        let (min, max) = (*bounds.first().unwrap(), *bounds.last().unwrap());
+        bounds.extend([min - 1, max + 1]);
+        bounds.into_iter().collect::<Vec<_>>()
+    };
+    let (ys, xs) = (bounds(|c| c.0), bounds(|c| c.1));
+    let index = |bounds: &[i64], v: i64| bounds.binary_search(&v).unwrap();
+    let mut trench = vec![vec![false; xs.len() - 1]; ys.len() - 1];
+    for w in corners.windows(2) {
+        let (y0, y1) = (index(&ys, w[0].0.min(w[1].0)), index(&ys, w[0].0.max(w[1].0)));
+        let (x0, x1) = (index(&xs, w[0].1.min(w[1].1)), index(&xs, w[0].1.max(w[1].1)));
+        for row in &mut trench[y0..=y1] {
+            row[x0..=x1].fill(true);
+        }
+    }
+    let mut outside = vec![vec![false; xs.len() - 1]; ys.len() - 1];
+    let mut stack = vec![(0_usize, 0_usize)];
+    outside[0][0] = true;
+    while let Some((row, col)) = stack.pop() {
+        for (r, c) in [(row.wrapping_sub(1), col), (row + 1, col), (row, col.wrapping_sub(1)), (row, col + 1)] {
+            if r < ys.len() - 1 && c < xs.len() - 1 && !trench[r][c] && !outside[r][c] {
+                outside[r][c] = true;
+                stack.push((r, c));
+            }
+        }
+    }
+    let mut area = 0;
+    for row in 0..ys.len() - 1 {
+        for col in 0..xs.len() - 1 {
+            if !outside[row][col] {
+                area += (ys[row + 1] - ys[row]) * (xs[col + 1] - xs[col]);
+            }
+        }
+    }
+    area
+}
+
+pub fn part_1(inp: &str) -> i64 {
+    let plan: Vec<(char, i64)> = inp
+        .lines()
+        .map(|line| {
+            let mut words = line.split(' ');
+            let dir = words.next().unwrap().chars().next().unwrap();
+            (dir, words.next().unwrap().parse().unwrap())
+        })
+        .collect();
+    dig(&plan)
+}
+
+pub fn part_2(inp: &str) -> i64 {
+    let plan: Vec<(char, i64)> = inp
+        .lines()
+        .map(|line| {
+            let color = line.split_once('#').unwrap().1;
+            let dir = ['R', 'D', 'L', 'U'][color[5..6].parse::<usize>().unwrap()];
+            (dir, i64::from_str_radix(&color[..5], 16).unwrap())
+        })
+        .collect();
+    dig(&plan)
+}
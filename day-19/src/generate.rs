@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// A workflow's rules as `(condition, target)`, and where the rest of the parts go.
+struct Workflow {
+    rules: Vec<(String, String)>,
+    fallback: String,
+}
+
+/// `size` workflows and `size` parts. The workflows form a tree under `in`, each sending parts to
+/// workflows below it or accepting or rejecting them, so every part ends up accepted or rejected.
+#[generator(size = 500, test_size = 20)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut labels = vec!["in".to_string()];
+    let mut taken: HashSet<String> = labels.iter().cloned().collect();
+    while labels.len() < size.max(1) {
+        let label: String = (0..rng.usize(2..=3)).map(|_| rng.lowercase()).collect();
+        if taken.insert(label.clone()) {
+            labels.push(label);
+        }
+    }
+
+    // Every later workflow hangs from a free target of one before it.
+    let mut workflows: Vec<Workflow> = Vec::new();
+    let mut free: Vec<(usize, Option<usize>)> = Vec::new();
+    for (i, label) in labels.iter().enumerate() {
+        if i > 0 {
+            let (parent, target) = free.swap_remove(rng.usize(..free.len()));
+            match target {
+                Some(rule) => workflows[parent].rules[rule].1 = label.clone(),
+                None => workflows[parent].fallback = label.clone(),
+            }
+        }
+        let rules: Vec<(String, String)> = (0..rng.usize(1..=3))
+            .map(|rule| {
+                free.push((i, Some(rule)));
+                let category = b"xmas"[rng.usize(..4)] as char;
+                let op = if rng.bool() { '<' } else { '>' };
+                (format!("{}{}{}", category, op, rng.u32(2..4000)), String::new())
+            })
+            .collect();
+        free.push((i, None));
+        workflows.push(Workflow { rules, fallback: String::new() });
+    }
+    let mut end = |target: &mut String| {
+        if target.is_empty() {
+            *target = if rng.bool() { "A" } else { "R" }.to_string();
+        }
+    };
+    for workflow in &mut workflows {
+        workflow.rules.iter_mut().for_each(|(_, target)| end(target));
+        end(&mut workflow.fallback);
+    }
+
+    let mut lines: Vec<String> = workflows
+        .iter()
+        .zip(&labels)
+        .map(|(workflow, label)| {
+            let rules: String = workflow.rules.iter().map(|(condition, target)| format!("{}:{},", condition, target)).collect();
+            format!("{}{{{}{}}}", label, rules, workflow.fallback)
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+    let parts: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.u32(1..=4000));
+            format!("{{x={},m={},a={},s={}}}", x, m, a, s)
+        })
+        .collect();
+    format!("{}\n\n{}", lines.join("\n"), parts.join("\n"))
+}
@@ -0,0 +1,286 @@
+use std::{collections::HashMap, mem::replace, str::FromStr};
+
+use aoc_core::{
+    parse::{field, lines, ParseError},
+    trace,
+};
+use aoc_macros::aoc;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+pub mod generate;
+pub mod reference;
+
+const REGEX_PATTERN: &str =
+    r#"(?P<label>\w+)\{(?P<workflow_lines>(\w+(<|>)\d+:\w+,)+)(?P<no_match>\w+)\}"#;
+
+const PART_PATTERN: &str = r"\{x=(?P<x>\d+),m=(?P<m>\d+),a=(?P<a>\d+),s=(?P<s>\d+)\}";
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(REGEX_PATTERN).unwrap();
+    static ref PART_RE: Regex = Regex::new(PART_PATTERN).unwrap();
+}
+
+#[derive(Clone)]
+enum WorkflowNextType {
+    Accept,
+    Reject,
+    Next(String),
+}
+
+impl From<&str> for WorkflowNextType {
+    fn from(value: &str) -> Self {
+        match value {
+            "A" => WorkflowNextType::Accept,
+            "R" => WorkflowNextType::Reject,
+            _ => WorkflowNextType::Next(value.to_string()),
+        }
+    }
+}
+
+enum WorkflowLineType {
+    Gt,
+    Lt,
+}
+
+enum PartType {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl FromStr for PartType {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "x" => Ok(PartType::X),
+            "m" => Ok(PartType::M),
+            "a" => Ok(PartType::A),
+            "s" => Ok(PartType::S),
+            _ => Err(ParseError::at(value, value, "x, m, a or s")),
+        }
+    }
+}
+
+struct WorkflowLine {
+    ty: WorkflowLineType,
+    next: WorkflowNextType,
+    part: PartType,
+    condition: u64,
+}
+
+impl FromStr for WorkflowLine {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (cond, next) = value
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(value, value, "`<condition>:<next>`"))?;
+        let (ty, (part, num)) = match (cond.split_once('<'), cond.split_once('>')) {
+            (Some(split), _) => (WorkflowLineType::Lt, split),
+            (_, Some(split)) => (WorkflowLineType::Gt, split),
+            _ => return Err(ParseError::at(value, cond, "a `<` or `>` condition")),
+        };
+        Ok(Self {
+            ty,
+            next: WorkflowNextType::from(next),
+            part: part.parse().map_err(|e: ParseError| e.inside(value, part))?,
+            condition: field(value, num, "a rating")?,
+        })
+    }
+}
+
+impl WorkflowLine {
+    fn apply(&self, part: &Part) -> Option<&WorkflowNextType> {
+        let value = match self.part {
+            PartType::X => part.x,
+            PartType::M => part.m,
+            PartType::A => part.a,
+            PartType::S => part.s,
+        };
+        let applies = match self.ty {
+            WorkflowLineType::Gt => value > self.condition,
+            WorkflowLineType::Lt => value < self.condition,
+        };
+        if applies {
+            Some(&self.next)
+        } else {
+            None
+        }
+    }
+}
+
+struct Workflow {
+    label: String,
+    lines: Vec<WorkflowLine>,
+    no_match: WorkflowNextType,
+}
+
+impl FromStr for Workflow {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mat = RE
+            .captures(value)
+            .ok_or_else(|| ParseError::at(value, value, "`<label>{<rules>,<fallback>}`"))?;
+        let lines = mat.name("workflow_lines").unwrap().as_str();
+        Ok(Self {
+            label: mat.name("label").unwrap().as_str().to_string(),
+            lines: lines
+                .split_terminator(',')
+                .map(|l| l.parse().map_err(|e: ParseError| e.inside(value, l)))
+                .collect::<Result<_, _>>()?,
+            no_match: WorkflowNextType::from(mat.name("no_match").unwrap().as_str()),
+        })
+    }
+}
+
+impl Workflow {
+    fn apply(&self, part: &Part) -> &WorkflowNextType {
+        self.lines
+            .iter()
+            .find_map(|l| l.apply(part))
+            .unwrap_or(&self.no_match)
+    }
+}
+
+struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+impl FromStr for Part {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mat = PART_RE
+            .captures(value)
+            .ok_or_else(|| ParseError::at(value, value, "`{x=<x>,m=<m>,a=<a>,s=<s>}`"))?;
+        let rating = |name| field(value, mat.name(name).unwrap().as_str(), "a rating");
+        Ok(Self {
+            x: rating("x")?,
+            m: rating("m")?,
+            a: rating("a")?,
+            s: rating("s")?,
+        })
+    }
+}
+
+/// The workflows by label, and the parts to sort with them.
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+pub fn parse(inp: &str) -> Result<System, ParseError> {
+    let (workflows, parts) = inp
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end(inp, "a blank line between the workflows and the parts"))?;
+    let workflows = lines::<Workflow>(workflows)?
+        .into_iter()
+        .map(|w| (w.label.clone(), w))
+        .collect();
+    let parts = lines(parts).map_err(|e| e.inside(inp, parts))?;
+    Ok(System { workflows, parts })
+}
+
+#[aoc(test = "19114", malformed = "in{s<1351:A,R}\n\n{x=787,m=2655,a=1222}", answer = "406849", reference = reference::part_1)]
+pub fn part_1(System { workflows, parts }: &System) -> u64 {
+    parts
+        .iter()
+        .filter(|p| {
+            let mut cur = "in";
+            loop {
+                let wf = workflows.get(cur).unwrap();
+                match wf.apply(p) {
+                    WorkflowNextType::Accept => return true,
+                    WorkflowNextType::Reject => return false,
+                    WorkflowNextType::Next(label) => {
+                        cur = label;
+                    }
+                }
+            }
+        })
+        .map(|p| p.x + p.m + p.a + p.s)
+        .sum()
+}
+type Range = [u64; 2];
+type Ranges = [Range; 4];
+
+/// How many parts have ratings in all four ranges.
+fn combinations(ranges: &Ranges) -> u64 {
+    ranges.iter().map(|r| r[1].saturating_sub(r[0] - 1)).product()
+}
+
+fn search(workflows: &HashMap<String, Workflow>, cur: &str, ranges: &mut Ranges) -> u64 {
+    if ranges.iter().any(|r| r[0] > r[1]) {
+        return 0;
+    }
+    let copy = *ranges;
+    let wf = workflows.get(cur).unwrap();
+    let mut sum: u64 = 0;
+    for line in &wf.lines {
+        let part = match line.part {
+            PartType::X => 0,
+            PartType::M => 1,
+            PartType::A => 2,
+            PartType::S => 3,
+        };
+        let bound = match line.ty {
+            WorkflowLineType::Gt => 0,
+            WorkflowLineType::Lt => 1,
+        };
+        // The rule narrows the range it gets, parts outside it were sent elsewhere already.
+        let copy = ranges[part][bound];
+        if bound == 0 {
+            ranges[part][bound] = copy.max(line.condition + 1);
+        } else {
+            ranges[part][bound] = copy.min(line.condition - 1);
+        }
+        trace!(
+            "split",
+            workflow = cur,
+            category = ["x", "m", "a", "s"][part],
+            min = ranges[part][0],
+            max = ranges[part][1],
+            combinations = combinations(ranges),
+        );
+
+        match &line.next {
+            WorkflowNextType::Accept => {
+                sum += combinations(ranges);
+            }
+            WorkflowNextType::Reject => {}
+            WorkflowNextType::Next(lab) => {
+                sum += search(workflows, lab, ranges);
+            }
+        }
+        ranges[part][bound] = copy;
+        if bound == 0 {
+            ranges[part][1] = ranges[part][1].min(line.condition);
+        } else {
+            ranges[part][0] = ranges[part][0].max(line.condition);
+        }
+    }
+    match &wf.no_match {
+        WorkflowNextType::Accept => {
+            sum += combinations(ranges);
+        }
+        WorkflowNextType::Reject => {}
+        WorkflowNextType::Next(lab) => {
+            sum += search(workflows, lab, ranges);
+        }
+    }
+    let _ = replace(ranges, copy);
+    sum
+}
+
+#[aoc(test = "167409079868000", malformed = "in{q<1351:A,R}\n\n", answer = "138625360533574", reference = reference::part_2)]
+pub fn part_2(system: &System) -> u64 {
+    let mut ranges = [[1_u64, 4000_u64]; 4];
+    search(&system.workflows, "in", &mut ranges)
+}
@@ -0,0 +1,88 @@
+//! Naive solvers that run parts through the workflows, one from every piece of each category.
+
+use std::collections::{BTreeSet, HashMap};
+
+/// A rule as `(category, op, value, target)`.
+type Rule<'a> = (usize, u8, u64, &'a str);
+
+fn workflows(inp: &str) -> HashMap<&str, (Vec<Rule<'_>>, &str)> {
+    let workflows = inp.split_once("\n\n").unwrap().0;
+    workflows
+        .lines()
+        .map(|line| {
+            let (label, rest) = line.split_once('{').unwrap();
+            let mut rules: Vec<&str> = rest.trim_end_matches('}').split(',').collect();
+            let fallback = rules.pop().unwrap();
+            let rules = rules
+                .into_iter()
+                .map(|rule| {
+                    let (condition, target) = rule.split_once(':').unwrap();
+                    let category = "xmas".find(&condition[..1]).unwrap();
+                    (category, condition.as_bytes()[1], condition[2..].parse().unwrap(), target)
+                })
+                .collect();
+            (label, (rules, fallback))
+        })
+        .collect()
+}
+
+/// Runs a part through the workflows from `in`.
+fn accepted(workflows: &HashMap<&str, (Vec<Rule>, &str)>, part: [u64; 4]) -> bool {
+    let mut label = "in";
+    loop {
+        let (rules, fallback) = &workflows[label];
+        let applies = |&&(category, op, value, _): &&Rule| match op {
+            b'<' => part[category] < value,
+            _ => part[category] > value,
+        };
+        label = rules.iter().find(applies).map_or(fallback, |rule| rule.3);
+        match label {
+            "A" => return true,
+            "R" => return false,
+            _ => {}
+        }
+    }
+}
+
+pub fn part_1(inp: &str) -> u64 {
+    let workflows = workflows(inp);
+    let parts = inp.split_once("\n\n").unwrap().1;
+    parts
+        .lines()
+        .map(|line| {
+            let mut ratings = line.trim_matches(['{', '}']).split(',').map(|r| r[2..].parse().unwrap());
+            [(); 4].map(|_| ratings.next().unwrap())
+        })
+        .filter(|&part| accepted(&workflows, part))
+        .map(|part| part.iter().sum::<u64>())
+        .sum()
+}
+
+/// Cuts each category at every value a rule compares it with, and runs one part from each
+/// combination of pieces through the workflows: all the parts in it go the same way.
+pub fn part_2(inp: &str) -> u64 {
+    let workflows = workflows(inp);
+    let mut starts = [(); 4].map(|_| BTreeSet::from([1, 4001]));
+    for (rules, _) in workflows.values() {
+        for &(category, op, value, _) in rules {
+            starts[category].insert(if op == b'<' { value } else { value + 1 }.clamp(1, 4001));
+        }
+    }
+    let pieces = starts.map(|starts| {
+        let starts: Vec<u64> = starts.into_iter().collect();
+        starts.windows(2).map(|w| (w[0], w[1] - w[0])).collect::<Vec<_>>()
+    });
+    let mut sum = 0;
+    for &(x, xs) in &pieces[0] {
+        for &(m, ms) in &pieces[1] {
+            for &(a, as_) in &pieces[2] {
+                for &(s, ss) in &pieces[3] {
+                    if accepted(&workflows, [x, m, a, s]) {
+                        sum += xs * ms * as_ * ss;
+                    }
+                }
+            }
+        }
+    }
+    sum
+}
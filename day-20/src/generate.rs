@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// Bits of every counter.
+const BITS: u32 = 12;
+
+fn is_prime(n: u32) -> bool {
+    (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+/// A two letter name not `taken` yet.
+fn name(rng: &mut Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..2).map(|_| rng.lowercase()).collect();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// Modules for `size` twelve bit counters, like in a real input. The broadcaster counts button
+/// presses on each counter's flip-flops, and a conjunction resets the counter once it reaches a
+/// prime above 2048, sending a low pulse through an inverter. A conjunction of all the inverters
+/// sends a low pulse to `rx` once all counters reset on the same press. Small inputs have a single
+/// counter, few enough presses to make one at a time.
+#[generator(size = 4, test_size = 1)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut taken = HashSet::from(["rx".to_string()]);
+    let last = name(rng, &mut taken);
+    let mut lines = vec![format!("&{} -> rx", last)];
+    let mut firsts = Vec::new();
+    let mut primes = HashSet::new();
+    for _ in 0..size.max(1) {
+        let prime = loop {
+            let n = rng.u32(1 << (BITS - 1)..1 << BITS);
+            if is_prime(n) && primes.insert(n) {
+                break n;
+            }
+        };
+        let flip_flops: Vec<String> = (0..BITS).map(|_| name(rng, &mut taken)).collect();
+        let (hub, inverter) = (name(rng, &mut taken), name(rng, &mut taken));
+        let mut resets = vec![flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs: Vec<&str> = flip_flops.get(bit + 1).map(String::as_str).into_iter().collect();
+            match prime >> bit & 1 {
+                1 => outputs.push(&hub),
+                _ => resets.push(flip_flop.clone()),
+            }
+            lines.push(format!("%{} -> {}", flip_flop, outputs.join(", ")));
+        }
+        resets.push(inverter.clone());
+        lines.push(format!("&{} -> {}", hub, resets.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, last));
+        firsts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
@@ -0,0 +1,264 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::{parse::ParseError, trace};
+use aoc_graph::lcm;
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug)]
+struct ReceivedPulse {
+    sender: String,
+    pulse: Pulse,
+}
+
+impl ReceivedPulse {
+    fn new(sender: String, pulse: Pulse) -> Self {
+        Self { sender, pulse }
+    }
+}
+
+trait Gate {
+    fn apply(&mut self, pulse: ReceivedPulse) -> Option<Pulse>;
+    fn label(&self) -> &str;
+    fn after_setup(&mut self, ins: Vec<String>);
+}
+
+struct Conjuction {
+    label: String,
+    ins: Vec<String>,
+    mem: HashMap<String, Pulse>,
+}
+
+impl Gate for Conjuction {
+    fn apply(&mut self, pulse: ReceivedPulse) -> Option<Pulse> {
+        *self.mem.get_mut(&pulse.sender).unwrap() = pulse.pulse;
+        if self.mem.iter().all(|(_k, v)| *v == Pulse::High) {
+            Some(Pulse::Low)
+        } else {
+            Some(Pulse::High)
+        }
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn after_setup(&mut self, ins: Vec<String>) {
+        for i in &ins {
+            self.mem.insert(i.to_string(), Pulse::Low);
+        }
+        self.ins = ins;
+    }
+}
+
+impl Conjuction {
+    fn new(label: String) -> Self {
+        Self {
+            label,
+            ins: vec![],
+            mem: HashMap::new(),
+        }
+    }
+}
+
+struct Broadcast {
+    label: String,
+}
+
+impl Gate for Broadcast {
+    fn apply(&mut self, pulse: ReceivedPulse) -> Option<Pulse> {
+        Some(pulse.pulse)
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn after_setup(&mut self, _ins: Vec<String>) {}
+}
+
+impl Broadcast {
+    fn new(label: String) -> Self {
+        Self { label }
+    }
+}
+
+struct FlipFlop {
+    label: String,
+    mem: bool,
+}
+
+impl Gate for FlipFlop {
+    fn apply(&mut self, pulse: ReceivedPulse) -> Option<Pulse> {
+        if pulse.pulse == Pulse::High {
+            return None;
+        }
+        self.mem = !self.mem;
+        match self.mem {
+            true => Some(Pulse::High),
+            false => Some(Pulse::Low),
+        }
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+
+    fn after_setup(&mut self, _ins: Vec<String>) {}
+}
+
+impl FlipFlop {
+    fn new(label: String) -> Self {
+        Self { label, mem: false }
+    }
+}
+
+/// A gate from its label with its type prefix, which `parse` made sure isn't empty.
+fn parse_gate(label: &str) -> Box<dyn Gate> {
+    match label.chars().next() {
+        Some('&') => Box::new(Conjuction::new(label.chars().skip(1).collect())),
+        Some('%') => Box::new(FlipFlop::new(label.chars().skip(1).collect())),
+        _ => Box::new(Broadcast::new(label.to_string())),
+    }
+}
+
+/// The modules as written, with their type prefix, and where each sends its pulses.
+pub struct Network {
+    modules: Vec<String>,
+    edges: HashMap<String, Vec<String>>,
+}
+
+impl Network {
+    /// Every gate in its initial state, by label.
+    fn gates(&self) -> HashMap<String, Box<dyn Gate>> {
+        let mut gates: HashMap<String, Box<dyn Gate>> = HashMap::new();
+        let mut conjunctions = HashSet::new();
+        for label in &self.modules {
+            let gate = parse_gate(label);
+            if label.starts_with('&') {
+                conjunctions.insert(gate.label().to_string());
+            }
+            gates.insert(gate.label().to_string(), gate);
+        }
+        for c in &conjunctions {
+            gates.get_mut(c).unwrap().after_setup(
+                self.edges
+                    .iter()
+                    .filter_map(|(i, out)| {
+                        if out.contains(c) {
+                            Some(i.to_string())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect(),
+            )
+        }
+        gates
+    }
+
+    /// Presses the button once, the `press`th time, and sends every pulse on until none are left,
+    /// calling `on_pulse` with the module each one is sent to before it arrives.
+    fn press(&self, gates: &mut HashMap<String, Box<dyn Gate>>, press: u64, mut on_pulse: impl FnMut(&str, &ReceivedPulse)) {
+        let mut queue = VecDeque::new();
+        queue.push_back((
+            "broadcaster".to_string(),
+            ReceivedPulse::new("button".to_string(), Pulse::Low),
+        ));
+        while let Some((gate, pulse)) = queue.pop_front() {
+            trace!("pulse", press = press, from = &pulse.sender, to = &gate, high = pulse.pulse == Pulse::High);
+            on_pulse(&gate, &pulse);
+            if let Some(gate) = gates.get_mut(&gate) {
+                if let Some(pulse) = gate.apply(pulse) {
+                    for g in self.edges.get(gate.label()).unwrap() {
+                        queue.push_back((
+                            g.to_string(),
+                            ReceivedPulse::new(gate.label().to_string(), pulse.clone()),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn parse(inp: &str) -> Result<Network, ParseError> {
+    let mut modules = Vec::new();
+    let mut edges = HashMap::new();
+    for line in inp.lines() {
+        let (label, out) = line
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::at(inp, line, "`<module> -> <destinations>`"))?;
+        if label.trim_start_matches(['&', '%']).is_empty() {
+            return Err(ParseError::at(inp, label, "a module name"));
+        }
+        edges.insert(
+            label.trim_start_matches(['&', '%']).to_string(),
+            out.split(", ").map(|s| s.to_string()).collect(),
+        );
+        modules.push(label.to_string());
+    }
+    Ok(Network { modules, edges })
+}
+
+#[aoc(test = "11687500", malformed = "broadcaster -> a\n%a b", malformed = "broadcaster -> a\n% -> b", answer = "800830848", reference = reference::part_1)]
+pub fn part_1(network: &Network) -> u64 {
+    let mut gates = network.gates();
+    let mut low_pulses = 0;
+    let mut high_pulses = 0;
+    for press in 1..=1000 {
+        network.press(&mut gates, press, |_, pulse| match pulse.pulse {
+            Pulse::Low => low_pulses += 1,
+            Pulse::High => high_pulses += 1,
+        });
+    }
+    low_pulses * high_pulses
+}
+
+#[aoc(answer = "244055946148853", reference = reference::part_2)]
+pub fn part_2(network: &Network) -> u64 {
+    let mut gates = network.gates();
+    let edges = &network.edges;
+    let rx = "rx".to_string();
+    let before_rx: String = edges
+        .iter()
+        .find_map(|(i, out)| {
+            if out.contains(&rx) {
+                Some(i.to_string())
+            } else {
+                None
+            }
+        })
+        .unwrap();
+    let before_rx: Vec<_> = edges
+        .iter()
+        .filter_map(|(i, out)| {
+            if out.contains(&before_rx) {
+                Some(i.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let mut presses = 0;
+    let mut cycles: HashMap<String, u64> = HashMap::new();
+    while cycles.len() < before_rx.len() {
+        presses += 1;
+        network.press(&mut gates, presses, |gate, pulse| {
+            if before_rx.iter().any(|b| b == gate) && pulse.pulse == Pulse::Low && !cycles.contains_key(gate) {
+                trace!("cycle", module = gate, presses = presses);
+                cycles.insert(gate.to_string(), presses);
+            }
+        });
+    }
+    lcm(cycles.into_values())
+}
@@ -0,0 +1,91 @@
+//! Naive solvers that press the button until `rx` gets a low pulse, without looking for cycles.
+
+use std::collections::{BTreeMap, VecDeque};
+
+/// The modules, their state, and the pulses one button press sends.
+struct Machine<'a> {
+    /// The type prefix of each module, and where it sends pulses.
+    modules: BTreeMap<&'a str, (char, Vec<&'a str>)>,
+    flip_flops: BTreeMap<&'a str, bool>,
+    /// The last pulse each conjunction got from each of its inputs, `true` for high.
+    conjunctions: BTreeMap<&'a str, BTreeMap<&'a str, bool>>,
+}
+
+impl<'a> Machine<'a> {
+    fn new(inp: &'a str) -> Self {
+        let mut modules = BTreeMap::new();
+        for line in inp.lines() {
+            let (module, targets) = line.split_once(" -> ").unwrap();
+            let (kind, label) = match module.strip_prefix(['%', '&']) {
+                Some(label) => (module.chars().next().unwrap(), label),
+                None => (' ', module),
+            };
+            modules.insert(label, (kind, targets.split(", ").collect::<Vec<_>>()));
+        }
+        let mut flip_flops = BTreeMap::new();
+        let mut conjunctions: BTreeMap<&str, BTreeMap<&str, bool>> = BTreeMap::new();
+        for (&label, (kind, _)) in &modules {
+            match kind {
+                '%' => _ = flip_flops.insert(label, false),
+                '&' => _ = conjunctions.insert(label, BTreeMap::new()),
+                _ => {}
+            }
+        }
+        for (&label, (_, targets)) in &modules {
+            for target in targets {
+                if let Some(inputs) = conjunctions.get_mut(target) {
+                    inputs.insert(label, false);
+                }
+            }
+        }
+        Machine { modules, flip_flops, conjunctions }
+    }
+
+    /// Presses the button, and returns every pulse sent as `(from, to, high)`.
+    fn press(&mut self) -> Vec<(&'a str, &'a str, bool)> {
+        let mut sent = Vec::new();
+        let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some((from, to, high)) = queue.pop_front() {
+            sent.push((from, to, high));
+            let Some((kind, targets)) = self.modules.get(to) else {
+                continue;
+            };
+            let out = match kind {
+                '%' if high => continue,
+                '%' => {
+                    let on = self.flip_flops.get_mut(to).unwrap();
+                    *on = !*on;
+                    *on
+                }
+                '&' => {
+                    let inputs = self.conjunctions.get_mut(to).unwrap();
+                    inputs.insert(from, high);
+                    !inputs.values().all(|&high| high)
+                }
+                _ => high,
+            };
+            queue.extend(targets.iter().map(|&target| (to, target, out)));
+        }
+        sent
+    }
+}
+
+pub fn part_1(inp: &str) -> u64 {
+    let mut machine = Machine::new(inp);
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..1000 {
+        for (_, _, pulse) in machine.press() {
+            match pulse {
+                true => high += 1,
+                false => low += 1,
+            }
+        }
+    }
+    low * high
+}
+
+/// Presses the button until `rx` gets a low pulse.
+pub fn part_2(inp: &str) -> u64 {
+    let mut machine = Machine::new(inp);
+    (1..).find(|_| machine.press().iter().any(|&(_, to, high)| to == "rx" && !high)).unwrap()
+}
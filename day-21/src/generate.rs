@@ -0,0 +1,24 @@
+use aoc_core::generate::Rng;
+use aoc_grid::{Grid, Point};
+use aoc_macros::generator;
+
+/// Side of the garden. The 26501365 steps of part 2 have to reach exactly halfway into a garden
+/// copy, and 131 is the only reasonable side for which they do.
+const SIDE: usize = 131;
+
+/// A garden with about `size` rocks and the start in its centre. Like in a real input, the start's
+/// row and column, the border and the diamond between the middles of its sides are clear of rocks.
+#[generator(size = 2000, test_size = 300)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let centre = SIDE / 2;
+    let mut garden = Grid::new(SIDE, SIDE, '.');
+    for _ in 0..size {
+        let p = Point::new(rng.usize(1..SIDE - 1), rng.usize(1..SIDE - 1));
+        let diamond = p.manhattan(Point::new(centre, centre)).abs_diff(centre);
+        if p.row != centre && p.col != centre && diamond > 1 {
+            garden[p] = '#';
+        }
+    }
+    garden[Point::new(centre, centre)] = 'S';
+    garden.to_string()
+}
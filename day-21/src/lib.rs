@@ -0,0 +1,97 @@
+use aoc_core::parse::ParseError;
+use aoc_graph::{distances, Graph};
+use aoc_grid::{Direction, Grid, RaggedRow};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+pub mod render;
+
+/// The garden, with the start replaced by a plot.
+pub struct Map {
+    garden: Grid<char>,
+    start: (isize, isize),
+}
+
+pub fn parse(inp: &str) -> Result<Map, ParseError> {
+    let mut garden: Grid<char> = inp.parse().map_err(|e: RaggedRow| ParseError::ragged(inp, e.row, e.width))?;
+    if let Some(p) = garden.position(|c| !matches!(c, '.' | '#' | 'S')) {
+        return Err(ParseError::cell(inp, p.row, p.col, "a plot ., a rock # or the start S"));
+    }
+    let start = garden.position(|&c| c == 'S').ok_or_else(|| ParseError::end(inp, "the start tile S"))?;
+    garden[start] = '.';
+    Ok(Map { garden, start: (start.row as isize, start.col as isize) })
+}
+
+#[aoc(test = "16", malformed = "...\n.S..\n...", malformed = "...\n...\n...", malformed = "...\n.S.\n.x.", test_params = 6, params = 64, answer = "3671", reference = reference::part_1, render = render::part_1)]
+pub fn part_1(map: &Map, steps: usize) -> usize {
+    calc(&map.garden, map.start, steps)
+}
+
+/// The garden repeated infinitely in every direction, with a step between neighbouring plots.
+struct Garden<'a>(&'a Grid<char>);
+
+impl Graph for Garden<'_> {
+    type Node = (isize, isize);
+
+    fn neighbours(&self, &(y, x): &(isize, isize)) -> impl IntoIterator<Item = (isize, isize)> {
+        Direction::ALL
+            .map(Direction::offset)
+            .into_iter()
+            .map(move |(dy, dx)| (y + dy, x + dx))
+            .filter(|&(y, x)| self.0.get_wrapping(y, x) == Some(&'.'))
+    }
+}
+
+/// Counts the plots reachable in exactly `steps` steps on an infinitely repeating garden. Those are
+/// the plots at most `steps` away with the same parity, as the elf can always step back and forth.
+fn calc(garden: &Grid<char>, start: (isize, isize), steps: usize) -> usize {
+    reachable_counts(garden, start, steps)[steps]
+}
+
+/// The number of plots reachable in exactly `steps` steps, for every number of steps up to `limit`.
+fn reachable_counts(garden: &Grid<char>, start: (isize, isize), limit: usize) -> Vec<usize> {
+    let mut counts = vec![0; limit + 1];
+    for d in distances(&Garden(garden), [start], limit).into_values() {
+        counts[d] += 1;
+    }
+    for steps in 2..=limit {
+        counts[steps] += counts[steps - 2];
+    }
+    counts
+}
+
+/// Once the walk has spread over a few copies of the garden, the counts a garden apart grow
+/// quadratically: their second differences stay the same. The walk goes on until two of them agree,
+/// and the rest is extrapolated from there.
+#[aoc(
+    cases(("test.txt", "16", 6), ("test.txt", "50", 10), ("test.txt", "6536", 100), ("test.txt", "16733044", 5000)),
+    params = 26501365,
+    answer = "609708004316870",
+    reference = reference::part_2,
+    reference_params = 458
+)]
+pub fn part_2(map: &Map, steps: usize) -> usize {
+    let (garden, start) = (&map.garden, map.start);
+    let size = garden.height();
+    let rest = steps % size;
+    let mut gardens = 3;
+    loop {
+        let limit = steps.min(rest + gardens * size);
+        let counts = reachable_counts(garden, start, limit);
+        if limit == steps {
+            return counts[steps];
+        }
+        let y: Vec<i64> = (0..=gardens).map(|i| counts[rest + i * size] as i64).collect();
+        let second: Vec<i64> = y.windows(3).map(|w| w[2] - 2 * w[1] + w[0]).collect();
+        if let [.., a, b] = second[..] {
+            if a == b {
+                // Every further garden adds one more first difference, which grows by `b` each time.
+                let x = ((steps - limit) / size) as i64;
+                let first = y[gardens] - y[gardens - 1];
+                return (y[gardens] + x * first + b * x * (x + 1) / 2) as usize;
+            }
+        }
+        gardens *= 2;
+    }
+}
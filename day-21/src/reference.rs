@@ -0,0 +1,67 @@
+//! Naive solvers that take the steps one at a time, over enough copies of the garden for part 2.
+
+use std::collections::VecDeque;
+
+fn garden(inp: &str) -> Vec<&[u8]> {
+    inp.lines().map(str::as_bytes).collect()
+}
+
+fn start(garden: &[&[u8]]) -> (usize, usize) {
+    let row = garden.iter().position(|row| row.contains(&b'S')).unwrap();
+    (row, garden[row].iter().position(|&c| c == b'S').unwrap())
+}
+
+/// Takes the steps one at a time, keeping every plot the elf could be on.
+pub fn part_1(inp: &str, steps: usize) -> usize {
+    let garden = garden(inp);
+    let (height, width) = (garden.len(), garden[0].len());
+    let mut on = vec![vec![false; width]; height];
+    let (row, col) = start(&garden);
+    on[row][col] = true;
+    for _ in 0..steps {
+        let mut next = vec![vec![false; width]; height];
+        for (row, cols) in on.iter().enumerate() {
+            for col in (0..width).filter(|&col| cols[col]) {
+                for (r, c) in [(row.wrapping_sub(1), col), (row + 1, col), (row, col.wrapping_sub(1)), (row, col + 1)] {
+                    if r < height && c < width && garden[r][c] != b'#' {
+                        next[r][c] = true;
+                    }
+                }
+            }
+        }
+        on = next;
+    }
+    on.iter().flatten().filter(|&&on| on).count()
+}
+
+/// Walks the repeated garden as far as the steps reach, and counts the plots at most `steps` away
+/// with the same parity.
+pub fn part_2(inp: &str, steps: usize) -> usize {
+    let garden = garden(inp);
+    let (height, width) = (garden.len(), garden[0].len());
+    let (row, col) = start(&garden);
+    // Copies of the garden around the original one, enough to walk `steps` in any direction.
+    let copies = steps / height.min(width) + 1;
+    let (rows, cols) = ((2 * copies + 1) * height, (2 * copies + 1) * width);
+    let mut distance = vec![vec![usize::MAX; cols]; rows];
+    let start = (copies * height + row, copies * width + col);
+    distance[start.0][start.1] = 0;
+    let mut queue = VecDeque::from([start]);
+    let mut count = 0;
+    while let Some((row, col)) = queue.pop_front() {
+        let d = distance[row][col];
+        if d % 2 == steps % 2 {
+            count += 1;
+        }
+        if d == steps {
+            continue;
+        }
+        for (r, c) in [(row.wrapping_sub(1), col), (row + 1, col), (row, col.wrapping_sub(1)), (row, col + 1)] {
+            if r < rows && c < cols && garden[r % height][c % width] != b'#' && distance[r][c] == usize::MAX {
+                distance[r][c] = d + 1;
+                queue.push_back((r, c));
+            }
+        }
+    }
+    count
+}
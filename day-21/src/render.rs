@@ -0,0 +1,32 @@
+//! Pictures of what the solvers work out, for `aoc run --render`.
+
+use std::collections::HashSet;
+
+use aoc_graph::Graph;
+use aoc_grid::render::{Frame, Pixel, Rgb};
+
+use crate::{Garden, Map};
+
+/// The plots the elf can be on after each step, up to `steps`, as green `O`s. Plots in the copies
+/// of the garden around it are left out.
+pub fn part_1(map: &Map, steps: usize) -> Vec<Frame> {
+    let garden = Garden(&map.garden);
+    let mut on = HashSet::from([map.start]);
+    let mut frames = Vec::new();
+    for step in 0..=steps {
+        if step > 0 {
+            on = on.iter().flat_map(|plot| garden.neighbours(plot)).collect();
+        }
+        let mut frame = map.garden.map(|&c| match c {
+            '#' => Pixel::new('#', Rgb::GREY),
+            _ => Pixel::new('.', Rgb::DARK),
+        });
+        for &(row, col) in &on {
+            if let Some(p) = map.garden.point(row, col) {
+                frame[p] = Pixel::new('O', Rgb::GREEN);
+            }
+        }
+        frames.push(frame);
+    }
+    frames
+}
@@ -0,0 +1,31 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// Width and depth of the area the bricks fall in, like in a real input.
+const FOOTPRINT: usize = 10;
+
+/// A snapshot of `size` bricks up to four cubes long. Every brick floats a little above the ones
+/// below it, so no two overlap.
+#[generator(size = 1200, test_size = 60)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut top = [[0; FOOTPRINT]; FOOTPRINT];
+    let mut bricks: Vec<String> = (0..size)
+        .map(|_| {
+            let len = rng.usize(0..4);
+            let (dx, dy, dz) = match rng.u8(0..3) {
+                0 => (len, 0, 0),
+                1 => (0, len, 0),
+                _ => (0, 0, len),
+            };
+            let (x, y) = (rng.usize(0..FOOTPRINT - dx), rng.usize(0..FOOTPRINT - dy));
+            let cells = || (x..=x + dx).flat_map(move |x| (y..=y + dy).map(move |y| (x, y)));
+            let z = cells().map(|(x, y)| top[x][y]).max().unwrap() + rng.usize(1..4);
+            for (x, y) in cells() {
+                top[x][y] = z + dz;
+            }
+            format!("{},{},{}~{},{},{}", x, y, z, x + dx, y + dy, z + dz)
+        })
+        .collect();
+    rng.shuffle(&mut bricks);
+    bricks.join("\n")
+}
@@ -0,0 +1,179 @@
+use std::str::FromStr;
+
+use aoc_core::parse::{field, lines, ParseError};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+type Scale = usize;
+
+#[derive(Eq, Hash, PartialEq, Clone, Debug)]
+enum Direction {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Eq, Hash, PartialEq, Clone, Debug)]
+pub struct Cube {
+    x: Scale,
+    y: Scale,
+    z: Scale,
+    dir: Direction,
+    end: Scale,
+}
+
+/// Parses `x,y,z`, a part of `line`.
+fn coordinates(line: &str, value: &str) -> Result<[Scale; 3], ParseError> {
+    let mut values = value.split(',');
+    let mut next = || match values.next() {
+        Some(x) => field(line, x, "a coordinate"),
+        None => Err(ParseError::end(value, "a coordinate").inside(line, value)),
+    };
+    let coordinates = [next()?, next()?, next()?];
+    match values.next() {
+        Some(x) => Err(ParseError::at(line, x, "`~` or the end of the line")),
+        None => Ok(coordinates),
+    }
+}
+
+impl FromStr for Cube {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (start, end) = value
+            .split_once('~')
+            .ok_or_else(|| ParseError::at(value, value, "`<x>,<y>,<z>~<x>,<y>,<z>`"))?;
+        let [x, y, z] = coordinates(value, start)?;
+        let [ex, ey, ez] = coordinates(value, end)?;
+        let (dir, end) = match (ex.checked_sub(x), ey.checked_sub(y), ez.checked_sub(z)) {
+            (Some(_), Some(0), Some(0)) => (Direction::X, ex),
+            (Some(0), Some(_), Some(0)) => (Direction::Y, ey),
+            (Some(0), Some(0), Some(_)) => (Direction::Z, ez),
+            _ => return Err(ParseError::at(value, end, "an end along one axis, not below the start")),
+        };
+        Ok(Self { x, y, z, dir, end })
+    }
+}
+
+impl Cube {
+    fn overlaps(&self, other: &Self) -> bool {
+        if !match (&self.dir, &other.dir) {
+            (Direction::X, Direction::X) => self.x <= other.end && other.x <= self.end,
+            (Direction::X, _) => self.x <= other.x && self.end >= other.x,
+            (_, Direction::X) => other.x <= self.x && other.end >= self.x,
+            (_, _) => self.x == other.x,
+        } {
+            return false;
+        }
+        if !match (&self.dir, &other.dir) {
+            (Direction::Y, Direction::Y) => self.y <= other.end && other.y <= self.end,
+            (Direction::Y, _) => self.y <= other.y && self.end >= other.y,
+            (_, Direction::Y) => other.y <= self.y && other.end >= self.y,
+            (_, _) => self.y == other.y,
+        } {
+            return false;
+        }
+        match (&self.dir, &other.dir) {
+            (Direction::Z, Direction::Z) => self.z <= other.end && other.z <= self.end,
+            (Direction::Z, _) => self.z <= other.z && self.end >= other.z,
+            (_, Direction::Z) => other.z <= self.z && other.end >= self.z,
+            (_, _) => self.z == other.z,
+        }
+    }
+}
+
+fn drop(index: usize, cubes: &[Cube]) -> Cube {
+    let mut cube = cubes.get(index).unwrap().clone();
+    while cube.z > 1 {
+        cube.z -= 1;
+        if cube.dir == Direction::Z {
+            cube.end -= 1;
+        }
+        if cubes
+            .iter()
+            .enumerate()
+            .any(|(i, c)| i != index && cube.overlaps(c))
+        {
+            cube.z += 1;
+            if cube.dir == Direction::Z {
+                cube.end += 1;
+            }
+            return cube;
+        }
+    }
+    cube
+}
+
+fn try_drop(cubes: &[Cube]) -> bool {
+    for c in 0..cubes.len() {
+        let cube = drop(c, cubes);
+        if cube != cubes[c] {
+            return false;
+        }
+    }
+    true
+}
+
+fn how_many_drop(mut cubes: Vec<Cube>) -> usize {
+    let mut count = 0;
+    for c in 0..cubes.len() {
+        let cube = drop(c, &cubes);
+        if cube != cubes[c] {
+            count += 1;
+        }
+        *cubes.get_mut(c).unwrap() = cube;
+    }
+    count
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Cube>, ParseError> {
+    lines(inp)
+}
+
+#[aoc(test = "5", malformed = "1,0,1~1,2,1\n0,0,2~2,0", answer = "522", bench = 3, reference = reference::part_1)]
+pub fn part_1(cubes: &[Cube]) -> usize {
+    let mut cubes = cubes.to_vec();
+    cubes.sort_by_key(|cube| cube.z);
+    (0..cubes.len()).for_each(|i| {
+        let cube = drop(i, &cubes);
+        *cubes.get_mut(i).unwrap() = cube;
+    });
+    let mut count = 0;
+    (0..cubes.len()).for_each(|i| {
+        if try_drop(
+            &cubes
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| i != *j)
+                .map(|(_, c)| c.clone())
+                .collect::<Vec<_>>(),
+        ) {
+            count += 1;
+        }
+    });
+    count
+}
+
+#[aoc(test = "7", malformed = "1,0,1~2,2,1", answer = "83519", bench = 3, reference = reference::part_2)]
+pub fn part_2(cubes: &[Cube]) -> usize {
+    let mut cubes = cubes.to_vec();
+    cubes.sort_by_key(|cube| cube.z);
+    (0..cubes.len()).for_each(|i| {
+        let cube = drop(i, &cubes);
+        *cubes.get_mut(i).unwrap() = cube;
+    });
+    let mut count = 0;
+    (0..cubes.len()).for_each(|i| {
+        count += how_many_drop(
+            cubes
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| i != *j)
+                .map(|(_, c)| c.clone())
+                .collect::<Vec<_>>(),
+        );
+    });
+    count
+}
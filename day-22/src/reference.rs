@@ -0,0 +1,71 @@
+//! Naive solvers that lower bricks a unit at a time and settle the rest again without each brick.
+
+use std::collections::HashSet;
+
+type Brick = Vec<(usize, usize, usize)>;
+
+/// Every brick as the cubes it's made of.
+fn bricks(inp: &str) -> Vec<Brick> {
+    inp.lines()
+        .map(|line| {
+            let (start, end) = line.split_once('~').unwrap();
+            let coordinates = |s: &str| -> Vec<usize> { s.split(',').map(|c| c.parse().unwrap()).collect() };
+            let (start, end) = (coordinates(start), coordinates(end));
+            let mut cubes = Vec::new();
+            for x in start[0]..=end[0] {
+                for y in start[1]..=end[1] {
+                    for z in start[2]..=end[2] {
+                        cubes.push((x, y, z));
+                    }
+                }
+            }
+            cubes
+        })
+        .collect()
+}
+
+/// Lowers bricks one unit at a time until none can fall any further, and returns how many fell.
+fn settle(bricks: &mut [Brick]) -> usize {
+    let mut taken: HashSet<(usize, usize, usize)> = bricks.iter().flatten().copied().collect();
+    let mut fell = vec![false; bricks.len()];
+    let mut moved = true;
+    while moved {
+        moved = false;
+        for (brick, fell) in bricks.iter_mut().zip(&mut fell) {
+            let free = |&(x, y, z): &(usize, usize, usize)| {
+                z > 1 && (!taken.contains(&(x, y, z - 1)) || brick.contains(&(x, y, z - 1)))
+            };
+            if brick.iter().all(free) {
+                brick.iter().for_each(|cube| _ = taken.remove(cube));
+                brick.iter_mut().for_each(|cube| cube.2 -= 1);
+                taken.extend(brick.iter().copied());
+                (*fell, moved) = (true, true);
+            }
+        }
+    }
+    fell.iter().filter(|&&fell| fell).count()
+}
+
+pub fn part_1(inp: &str) -> usize {
+    let mut bricks = bricks(inp);
+    settle(&mut bricks);
+    (0..bricks.len())
+        .filter(|&i| {
+            let mut rest = bricks.clone();
+            rest.remove(i);
+            settle(&mut rest) == 0
+        })
+        .count()
+}
+
+pub fn part_2(inp: &str) -> usize {
+    let mut bricks = bricks(inp);
+    settle(&mut bricks);
+    (0..bricks.len())
+        .map(|i| {
+            let mut rest = bricks.clone();
+            rest.remove(i);
+            settle(&mut rest)
+        })
+        .sum()
+}
@@ -0,0 +1,66 @@
+use aoc_core::generate::Rng;
+use aoc_grid::{Grid, Point};
+use aoc_macros::generator;
+
+/// Rows or columns of the junctions, each after a corridor from 2 to 38 tiles long.
+fn lines(rng: &mut Rng, n: usize, first: usize) -> Vec<usize> {
+    let mut lines = vec![first];
+    for i in 1..n {
+        lines.push(lines[i - 1] + rng.usize(3..40));
+    }
+    lines
+}
+
+/// A map of `size` by `size` junctions, with slopes leading right or down from every junction to
+/// the next and into every junction, so the slippery hike is a directed acyclic graph like in a
+/// real input. About a third of the corridors are walled off, leaving every junction a way in and
+/// a way out.
+#[generator(size = 6, test_size = 3)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = size.max(2);
+    let first = rng.usize(2..40);
+    let rows = lines(rng, n, first);
+    let cols = lines(rng, n, 1);
+    let mut map = Grid::new(cols[n - 1] + 2, rows[n - 1] + 2, '#');
+    let mut corridors = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            if j + 1 < n {
+                corridors.push(((i, j), (i, j + 1)));
+            }
+            if i + 1 < n {
+                corridors.push(((i, j), (i + 1, j)));
+            }
+        }
+    }
+    rng.shuffle(&mut corridors);
+    let mut outs = Grid::new(n, n, 0);
+    let mut ins = Grid::new(n, n, 0);
+    for &(from, to) in &corridors {
+        outs[Point::from(from)] += 1;
+        ins[Point::from(to)] += 1;
+    }
+    for &(from, to) in &corridors {
+        let (from, to) = (Point::from(from), Point::from(to));
+        if outs[from] > 1 && ins[to] > 1 && rng.u8(0..3) == 0 {
+            outs[from] -= 1;
+            ins[to] -= 1;
+            continue;
+        }
+        let start = Point::new(rows[from.row], cols[from.col]);
+        let end = Point::new(rows[to.row], cols[to.col]);
+        for row in start.row..=end.row {
+            for col in start.col..=end.col {
+                map[Point::new(row, col)] = '.';
+            }
+        }
+        let (slope, (drow, dcol)) = if from.row == to.row { ('>', (0, 1)) } else { ('v', (1, 0)) };
+        map[Point::new(start.row + drow, start.col + dcol)] = slope;
+        map[Point::new(end.row - drow, end.col - dcol)] = slope;
+    }
+    for row in 0..rows[0] {
+        map[Point::new(row, 1)] = '.';
+    }
+    map[Point::new(rows[n - 1] + 1, cols[n - 1])] = '.';
+    map.to_string()
+}
@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_core::parse::ParseError;
+use aoc_graph::{longest_dag_path, longest_simple_path};
+use aoc_grid::{Direction, Grid, Point, RaggedRow};
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+fn slope(dir: Direction) -> char {
+    match dir {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+type Edge = (usize, usize, usize);
+
+/// The map of the trails, with the path tiles in its top and bottom rows.
+pub struct Trails {
+    pub map: Grid<char>,
+    pub start: Point,
+    pub end: Point,
+}
+
+fn build_graph(trails: &Trails) -> (Vec<Point>, Vec<Edge>) {
+    let Trails { map, start, end } = trails;
+    let (start, end) = (*start, *end);
+    let mut queue = VecDeque::new();
+    queue.push_back((Point::new(start.row + 1, start.col), Direction::Down, 0, 1));
+    let mut v_n = 2;
+    let mut edges = vec![];
+    let mut vertices = vec![];
+    vertices.push(start);
+    vertices.push(end);
+    while let Some((p, from, mut v, mut dist)) = queue.pop_front() {
+        if p == end {
+            edges.push((v, 1, dist));
+            continue;
+        }
+        let mut next = Vec::new();
+        for dir in Direction::ALL {
+            if dir == from.opposite() {
+                //skip the previous
+                continue;
+            }
+            let Some(n) = map.step(p, dir) else {
+                continue;
+            };
+            if map[n] == '.' || map[n] == slope(dir) {
+                next.push(dir);
+            }
+        }
+        if next.len() > 1 {
+            let v_i = vertices.iter().position(|v| *v == p).unwrap_or(v_n);
+            edges.push((v, v_i, dist));
+            if v_i != v_n {
+                continue;
+            }
+            vertices.push(p);
+            v = v_n;
+            v_n += 1;
+            dist = 0;
+        };
+        for dir in next {
+            queue.push_back((map.step(p, dir).unwrap(), dir, v, dist + 1))
+        }
+    }
+    (vertices, edges)
+}
+
+type Graph = HashMap<Point, Vec<(Point, usize)>>;
+
+/// Builds the graph of crossings, joining the corridors between them into single edges.
+fn build_cyclic_graph(map: &Grid<char>) -> Graph {
+    let mut graph: HashMap<Point, HashSet<(Point, usize)>> = HashMap::new();
+    for (p, &c) in map.iter() {
+        if c == '#' {
+            continue;
+        }
+        let new_vertex: HashSet<_> = map
+            .neighbours(p)
+            .filter(|&n| map[n] != '#')
+            .map(|n| (n, 1))
+            .collect();
+        graph.insert(p, new_vertex);
+    }
+    let mut update = false;
+    while !update {
+        update = true;
+        for key in graph.keys().copied().collect::<Vec<_>>() {
+            if graph[&key].len() != 2 {
+                continue;
+            }
+
+            let mut iter = graph[&key].iter();
+            let (a, a_dist) = *iter.next().unwrap();
+            let (b, b_dist) = *iter.next().unwrap();
+
+            let a_point = graph.get_mut(&a).unwrap();
+            a_point.retain(|(pos, _)| *pos != key);
+            a_point.insert((b, a_dist + b_dist));
+
+            let b_point = graph.get_mut(&b).unwrap();
+            b_point.retain(|(pos, _)| *pos != key);
+            b_point.insert((a, a_dist + b_dist));
+
+            graph.remove(&key);
+
+            update = false
+        }
+    }
+    graph
+        .into_iter()
+        .map(|(p, edges)| (p, edges.into_iter().collect()))
+        .collect()
+}
+
+pub fn parse(inp: &str) -> Result<Trails, ParseError> {
+    let map: Grid<char> = inp.parse().map_err(|e: RaggedRow| ParseError::ragged(inp, e.row, e.width))?;
+    if let Some(p) = map.position(|c| !matches!(c, '#' | '.' | '^' | '>' | 'v' | '<')) {
+        return Err(ParseError::cell(inp, p.row, p.col, "a path, a forest or a slope"));
+    }
+    let path = |row: usize| {
+        let col = map.row(row).iter().position(|&c| c == '.');
+        col.map(|col| Point::new(row, col)).ok_or_else(|| ParseError::cell(inp, row, 0, "a row with a path tile ."))
+    };
+    if map.height() == 0 {
+        return Err(ParseError::end(inp, "a row with a path tile ."));
+    }
+    let (start, end) = (path(0)?, path(map.height() - 1)?);
+    Ok(Trails { map, start, end })
+}
+
+#[aoc(test = "94", malformed = "#.#\n#..#", malformed = "#.#\n###", malformed = "#.#\n#.#\n#x#", answer = "2010", bench = 5, reference = reference::part_1)]
+pub fn part_1(trails: &Trails) -> usize {
+    let (_, edges) = build_graph(trails);
+    let mut graph: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+    for (a, b, dist) in edges {
+        graph.entry(a).or_default().push((b, dist));
+    }
+    longest_dag_path(&graph, 0, 1).unwrap()
+}
+
+#[aoc(test = "154", answer = "6318", bench = 5, reference = reference::part_2)]
+pub fn part_2(trails: &Trails) -> usize {
+    let graph = build_cyclic_graph(&trails.map);
+    longest_simple_path(&graph, trails.start, trails.end).unwrap()
+}
@@ -0,0 +1,50 @@
+//! Naive solvers that try every hike a tile at a time instead of between the crossings.
+
+/// `(row, col)` steps of the directions up, right, down and left, and the slopes leading that way.
+const STEPS: [(isize, isize, u8); 4] = [(-1, 0, b'^'), (0, 1, b'>'), (1, 0, b'v'), (0, -1, b'<')];
+
+/// Tries every hike one tile at a time, backtracking with an explicit stack as the hikes are too
+/// long to recurse along. On `slippery` slopes the next step has to go downhill.
+fn longest_hike(inp: &str, slippery: bool) -> usize {
+    let map: Vec<&[u8]> = inp.lines().map(str::as_bytes).collect();
+    let (height, width) = (map.len(), map[0].len());
+    let start = (0, map[0].iter().position(|&c| c == b'.').unwrap());
+    let mut visited = vec![vec![false; width]; height];
+    visited[start.0][start.1] = true;
+    // Every tile of the hike so far, and the direction to try next from it.
+    let mut hike = vec![(start, 0)];
+    let mut longest = 0;
+    while let Some(&((row, col), dir)) = hike.last() {
+        if row == height - 1 {
+            longest = longest.max(hike.len() - 1);
+        }
+        if dir == 4 || row == height - 1 {
+            visited[row][col] = false;
+            hike.pop();
+            continue;
+        }
+        let (dr, dc, slope) = STEPS[dir];
+        hike.last_mut().unwrap().1 += 1;
+        if slippery && map[row][col] != b'.' && map[row][col] != slope {
+            continue;
+        }
+        let (r, c) = (row as isize + dr, col as isize + dc);
+        if r < 0 || c < 0 || r as usize >= height || c as usize >= width {
+            continue;
+        }
+        let (r, c) = (r as usize, c as usize);
+        if map[r][c] != b'#' && !visited[r][c] {
+            visited[r][c] = true;
+            hike.push(((r, c), 0));
+        }
+    }
+    longest
+}
+
+pub fn part_1(inp: &str) -> usize {
+    longest_hike(inp, true)
+}
+
+pub fn part_2(inp: &str) -> usize {
+    longest_hike(inp, false)
+}
@@ -0,0 +1,25 @@
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// `size` hailstones, at least three, all hit by one rock thrown from around the middle of the test
+/// area. Every hailstone moves along both x and y, like in a real input.
+#[generator(size = 300, test_size = 20)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rock: [i64; 3] = [(); 3].map(|_| rng.i64(250_000_000_000_000..350_000_000_000_000));
+    let speed: [i64; 3] = [(); 3].map(|_| rng.i64(-100..=100));
+    let lines: Vec<String> = (0..size.max(3))
+        .map(|_| {
+            let velocity = loop {
+                let v = [(); 3].map(|_| rng.i64(-100..=100));
+                if v[0] != 0 && v[1] != 0 && v != speed {
+                    break v;
+                }
+            };
+            let time = rng.i64(100_000_000_000..300_000_000_000);
+            let p: Vec<i64> = (0..3).map(|i| rock[i] + (speed[i] - velocity[i]) * time).collect();
+            let v = velocity;
+            format!("{}, {}, {} @ {}, {}, {}", p[0], p[1], p[2], v[0], v[1], v[2])
+        })
+        .collect();
+    lines.join("\n")
+}
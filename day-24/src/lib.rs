@@ -0,0 +1,178 @@
+use aoc_core::parse::{field, ParseError};
+use aoc_macros::aoc;
+use itertools::Itertools;
+use num::{traits::ToPrimitive, BigInt, BigRational, Zero};
+
+pub mod generate;
+pub mod reference;
+
+type Coor<T> = (T, T, T);
+type P1Type = f64;
+type P1Coor = Coor<P1Type>;
+
+/// A hailstone's position and velocity.
+pub type Hailstone = (Coor<i64>, Coor<i64>);
+
+/// The three numbers of `part`, a part of `inp` like `19, 13, 30`.
+fn parse_coor(inp: &str, part: &str) -> Result<Coor<i64>, ParseError> {
+    let mut numbers = part.split(',').map(|x| field(inp, x.trim(), "a number"));
+    match (numbers.next(), numbers.next(), numbers.next(), numbers.next()) {
+        (Some(x), Some(y), Some(z), None) => Ok((x?, y?, z?)),
+        _ => Err(ParseError::at(inp, part, "`<x>, <y>, <z>`")),
+    }
+}
+
+fn parse_line(inp: &str, line: &str) -> Result<Hailstone, ParseError> {
+    let (p, v) = line
+        .split_once(" @ ")
+        .ok_or_else(|| ParseError::at(inp, line, "`<position> @ <velocity>`"))?;
+    Ok((parse_coor(inp, p)?, parse_coor(inp, v)?))
+}
+
+pub fn parse(inp: &str) -> Result<Vec<Hailstone>, ParseError> {
+    inp.lines().map(|line| parse_line(inp, line)).collect()
+}
+
+fn convert<T>((x, y, z): Coor<i64>, f: impl Fn(i64) -> T) -> Coor<T> {
+    (f(x), f(y), f(z))
+}
+
+fn get_intersect(l1: (P1Coor, P1Coor), l2: (P1Coor, P1Coor)) -> Option<(P1Type, P1Type)> {
+    let ((px1, py1, _), (vx1, vy1, _)) = l1;
+    let ((px2, py2, _), (vx2, vy2, _)) = l2;
+    let s1 = vy1 / vx1;
+    let s2 = vy2 / vx2;
+    if s1 == s2 {
+        return None;
+    }
+    let x = ((s2 * px2 - s1 * px1) - (py2 - py1)) / (s2 - s1);
+    let y = (vy1 * (x - px1)) / vx1 + py1;
+    let first_in_future = (x - px1).signum() == vx1.signum();
+    let second_in_future = (x - px2).signum() == vx2.signum();
+    if !first_in_future || !second_in_future {
+        return None;
+    }
+    Some((x, y))
+}
+
+pub struct TestArea {
+    pub lower: P1Type,
+    pub upper: P1Type,
+}
+
+#[aoc(
+    test = "2",
+    malformed = "19, 13, 30 @ -2, 1",
+    malformed = "19, 13, 30 -2, 1, -2",
+    test_params = TestArea { lower: 7.0, upper: 27.0 },
+    params = TestArea { lower: 200000000000000.0, upper: 400000000000000.0 },
+    answer = "11995",
+    reference = reference::part_1
+)]
+pub fn part_1(hailstones: &[Hailstone], area: TestArea) -> u64 {
+    let lines: Vec<(P1Coor, P1Coor)> = hailstones
+        .iter()
+        .map(|&(p, v)| (convert(p, |x| x as P1Type), convert(v, |x| x as P1Type)))
+        .collect();
+    lines
+        .iter()
+        .combinations(2)
+        .filter(|v| {
+            let l1 = v[0];
+            let l2 = v[1];
+            let point = get_intersect(*l1, *l2);
+            if point.is_none() {
+                return false;
+            }
+            let (x, y) = point.unwrap();
+            (area.lower..=area.upper).contains(&x) && (area.lower..=area.upper).contains(&y)
+        })
+        .count()
+        .try_into()
+        .unwrap()
+}
+
+/// Cross product of `a` and `b` as the rows of the matrix `m` with `a × b = m b`.
+fn cross_matrix(a: Coor<i128>) -> [[i128; 3]; 3] {
+    let (x, y, z) = a;
+    [[0, -z, y], [z, 0, -x], [-y, x, 0]]
+}
+
+fn cross(a: Coor<i128>, b: Coor<i128>) -> Coor<i128> {
+    (a.1 * b.2 - a.2 * b.1, a.2 * b.0 - a.0 * b.2, a.0 * b.1 - a.1 * b.0)
+}
+
+fn sub(a: Coor<i128>, b: Coor<i128>) -> Coor<i128> {
+    (a.0 - b.0, a.1 - b.1, a.2 - b.2)
+}
+
+/// Solves `m x = b` exactly, or returns `None` when `m` is singular.
+fn solve(mut m: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = m.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !m[r][col].is_zero())?;
+        m.swap(col, pivot);
+        let pivot = m[col].clone();
+        for (r, row) in m.iter_mut().enumerate() {
+            if r != col && !row[col].is_zero() {
+                let f = &row[col] / &pivot[col];
+                for (x, p) in row.iter_mut().zip(&pivot).skip(col) {
+                    *x -= &f * p;
+                }
+            }
+        }
+    }
+    Some((0..n).map(|r| &m[r][n] / &m[r][r]).collect())
+}
+
+/// The rock `(P, V)` hits hailstone `(p, v)` when `(P - p) × (V - v) = 0`. Subtracting that
+/// equation for two hailstones removes the `P × V` term, which leaves
+/// `P × (v' - v) + (p' - p) × V = p' × v' - p × v`: three linear equations per pair.
+#[aoc(test = "47", answer = "983620716335751", reference = reference::part_2)]
+pub fn part_2(hailstones: &[Hailstone]) -> i64 {
+    let lines: Vec<(Coor<i128>, Coor<i128>)> = hailstones
+        .iter()
+        .map(|&(p, v)| (convert(p, i128::from), convert(v, i128::from)))
+        .collect();
+    let (p0, v0) = lines[0];
+    let rows = |(p, v): (Coor<i128>, Coor<i128>)| {
+        let pm = cross_matrix(sub(p, p0));
+        let vm = cross_matrix(sub(v, v0));
+        let (rx, ry, rz) = sub(cross(p, v), cross(p0, v0));
+        (0..3).map(move |i| {
+            let rhs = [rx, ry, rz][i];
+            // P × w = -(w × P)
+            (0..3)
+                .map(|j| -vm[i][j])
+                .chain((0..3).map(|j| pm[i][j]))
+                .chain(std::iter::once(rhs))
+                .map(|x| BigRational::from_integer(BigInt::from(x)))
+                .collect::<Vec<_>>()
+        })
+    };
+    let solution = lines[1..]
+        .iter()
+        .tuple_combinations()
+        .find_map(|(&a, &b)| solve(rows(a).chain(rows(b)).collect()))
+        .unwrap();
+    solution[..3].iter().map(|x| x.to_integer().to_i64().unwrap()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_core::input::read_file;
+
+    use super::*;
+
+    /// Part 2 solves a linear system where it used to ask z3, which the brute force reference
+    /// has to agree with on the example and the real input.
+    #[test]
+    fn part_2_matches_reference() {
+        for file in ["test.txt", "input.txt"] {
+            let inp = read_file(Path::new(env!("CARGO_MANIFEST_DIR")).join(file)).unwrap();
+            assert_eq!(part_2(&parse(&inp).unwrap()), reference::part_2(&inp), "{}", file);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Naive solvers that intersect every pair of paths and try every rock velocity along x and y.
+
+use crate::TestArea;
+
+type Coor = [i128; 3];
+
+fn hailstones(inp: &str) -> Vec<(Coor, Coor)> {
+    inp.lines()
+        .map(|line| {
+            let numbers: Vec<i128> = line.split([',', '@']).map(|n| n.trim().parse().unwrap()).collect();
+            ([numbers[0], numbers[1], numbers[2]], [numbers[3], numbers[4], numbers[5]])
+        })
+        .collect()
+}
+
+/// Whether `numerator / denominator` lies within `lower..=upper`.
+fn within(numerator: i128, denominator: i128, lower: i128, upper: i128) -> bool {
+    let (numerator, denominator) = if denominator < 0 { (-numerator, -denominator) } else { (numerator, denominator) };
+    lower * denominator <= numerator && numerator <= upper * denominator
+}
+
+/// Intersects the paths in whole numbers, keeping the fractions of the crossing times.
+pub fn part_1(inp: &str, area: TestArea) -> u64 {
+    let hailstones = hailstones(inp);
+    let (lower, upper) = (area.lower as i128, area.upper as i128);
+    let mut count = 0;
+    for (i, &(p, v)) in hailstones.iter().enumerate() {
+        for &(q, w) in &hailstones[i + 1..] {
+            // p + t v = q + s w, with t = t_num / det and s = s_num / det.
+            let det = w[0] * v[1] - v[0] * w[1];
+            if det == 0 {
+                continue;
+            }
+            let (dx, dy) = (q[0] - p[0], q[1] - p[1]);
+            let t_num = w[0] * dy - w[1] * dx;
+            let s_num = v[0] * dy - v[1] * dx;
+            if t_num * det.signum() <= 0 || s_num * det.signum() <= 0 {
+                continue;
+            }
+            if within(p[0] * det + t_num * v[0], det, lower, upper) && within(p[1] * det + t_num * v[1], det, lower, upper) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Tries every rock velocity along x and y up to `LIMIT`. Seen from the rock, the hailstones all
+/// fly through the point it's thrown from, so two of them give the point, the times they're hit
+/// and from those the rock's velocity along z, and the rest have to agree.
+pub fn part_2(inp: &str) -> i64 {
+    // Real inputs throw the rock at up to a few hundred along an axis.
+    const LIMIT: i128 = 500;
+    let hailstones = hailstones(inp);
+    for vx in -LIMIT..=LIMIT {
+        for vy in -LIMIT..=LIMIT {
+            if let Some(rock) = throw(&hailstones, vx, vy) {
+                return rock.iter().sum::<i128>() as i64;
+            }
+        }
+    }
+    unreachable!("No rock velocity up to {} hits every hailstone", LIMIT)
+}
+
+/// The position of a rock thrown with velocity `vx`, `vy` along x and y that hits every
+/// hailstone, if there is one.
+fn throw(hailstones: &[(Coor, Coor)], vx: i128, vy: i128) -> Option<Coor> {
+    let relative = |v: Coor| [v[0] - vx, v[1] - vy];
+    for (i, &(p, v)) in hailstones.iter().enumerate() {
+        for &(q, w) in &hailstones[i + 1..] {
+            let (a, b) = (relative(v), relative(w));
+            let det = b[0] * a[1] - a[0] * b[1];
+            if det == 0 {
+                continue;
+            }
+            let (dx, dy) = (q[0] - p[0], q[1] - p[1]);
+            let (t_num, s_num) = (b[0] * dy - b[1] * dx, a[0] * dy - a[1] * dx);
+            if t_num % det != 0 || s_num % det != 0 {
+                return None;
+            }
+            let (t, s) = (t_num / det, s_num / det);
+            if t == s || (q[2] + s * w[2] - p[2] - t * v[2]) % (s - t) != 0 {
+                return None;
+            }
+            let vz = (q[2] + s * w[2] - p[2] - t * v[2]) / (s - t);
+            let speed = [vx, vy, vz];
+            let rock: Coor = [0, 1, 2].map(|k| p[k] + t * (v[k] - speed[k]));
+            let hits = hailstones.iter().all(|&(p, v)| {
+                let (d, u) = ([0, 1, 2].map(|k| rock[k] - p[k]), [0, 1, 2].map(|k| v[k] - speed[k]));
+                d[1] * u[2] == d[2] * u[1] && d[2] * u[0] == d[0] * u[2] && d[0] * u[1] == d[1] * u[0]
+            });
+            return hits.then_some(rock);
+        }
+    }
+    None
+}
@@ -0,0 +1,50 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use aoc_core::generate::Rng;
+use aoc_macros::generator;
+
+/// `size` components, at least ten, in two groups joined by exactly three wires. Every component
+/// is wired to the two next ones in its group and a few random others, so cutting any other three
+/// wires leaves both groups whole.
+#[generator(size = 1500, test_size = 16)]
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(10);
+    let mut taken = HashSet::new();
+    let names: Vec<String> = (0..size)
+        .map(|_| loop {
+            let name: String = (0..3).map(|_| rng.lowercase()).collect();
+            if taken.insert(name.clone()) {
+                break name;
+            }
+        })
+        .collect();
+    let split = rng.usize(5..=size - 5);
+    let mut wires = BTreeSet::new();
+    for (start, len) in [(0, split), (split, size - split)] {
+        for i in 0..len {
+            for next in [i + 1, i + 2].into_iter().chain((0..2).map(|_| rng.usize(0..len))) {
+                let (a, b) = (start + i, start + next % len);
+                if a != b {
+                    wires.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+    }
+    let mut cut = 0;
+    while cut < 3 {
+        if wires.insert((rng.usize(0..split), rng.usize(split..size))) {
+            cut += 1;
+        }
+    }
+    let mut lines: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for &(a, b) in &wires {
+        let (a, b) = if rng.bool() { (a, b) } else { (b, a) };
+        lines.entry(a).or_default().push(&names[b]);
+    }
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|(a, ends)| format!("{}: {}", names[a], ends.join(" ")))
+        .collect();
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
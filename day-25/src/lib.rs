@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+use aoc_core::parse::ParseError;
+use aoc_graph::min_cut;
+use aoc_macros::aoc;
+
+pub mod generate;
+pub mod reference;
+
+/// Every component with the components it is connected to, each with a weight of 1.
+pub type Wiring = HashMap<String, Vec<(String, usize)>>;
+
+/// Every component with its connections, listed both ways.
+pub fn parse(inp: &str) -> Result<Wiring, ParseError> {
+    let mut graph: Wiring = HashMap::new();
+    for line in inp.lines() {
+        let (start, end) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::at(inp, line, "`<component>: <components>`"))?;
+        for end in end.split(' ') {
+            if end.is_empty() {
+                return Err(ParseError::at(inp, end, "a component"));
+            }
+            graph.entry(start.to_string()).or_default().push((end.to_string(), 1));
+            graph.entry(end.to_string()).or_default().push((start.to_string(), 1));
+        }
+    }
+    Ok(graph)
+}
+
+#[aoc(test = "54", malformed = "jqt rhn xhk", malformed = "jqt: rhn  xhk", answer = "546804", reference = reference::part_1)]
+pub fn part_1(graph: &Wiring) -> usize {
+    let (cut, side) = min_cut(graph, graph.keys().cloned());
+    assert_eq!(cut, 3, "Expected to cut three wires");
+    side.len() * (graph.len() - side.len())
+}
@@ -0,0 +1,53 @@
+//! Naive solvers that cut every three wires in turn until the components fall apart.
+
+use std::collections::HashMap;
+
+/// Cuts every three wires in turn until the components fall apart.
+pub fn part_1(inp: &str) -> usize {
+    let mut ids: HashMap<&str, usize> = HashMap::new();
+    let mut wires = Vec::new();
+    for line in inp.lines() {
+        let (start, ends) = line.split_once(": ").unwrap();
+        for end in ends.split(' ') {
+            let next = ids.len();
+            let a = *ids.entry(start).or_insert(next);
+            let next = ids.len();
+            let b = *ids.entry(end).or_insert(next);
+            wires.push((a, b));
+        }
+    }
+    let n = ids.len();
+    // The number of components still connected to the first one without the `cut` wires.
+    let reached = |cut: [usize; 3]| {
+        let mut neighbours = vec![Vec::new(); n];
+        for (i, &(a, b)) in wires.iter().enumerate() {
+            if !cut.contains(&i) {
+                neighbours[a].push(b);
+                neighbours[b].push(a);
+            }
+        }
+        let mut seen = vec![false; n];
+        seen[0] = true;
+        let mut stack = vec![0];
+        while let Some(a) = stack.pop() {
+            for &b in &neighbours[a] {
+                if !seen[b] {
+                    seen[b] = true;
+                    stack.push(b);
+                }
+            }
+        }
+        seen.iter().filter(|&&seen| seen).count()
+    };
+    for a in 0..wires.len() {
+        for b in a + 1..wires.len() {
+            for c in b + 1..wires.len() {
+                let side = reached([a, b, c]);
+                if side < n {
+                    return side * (n - side);
+                }
+            }
+        }
+    }
+    unreachable!("No three wires split the components")
+}